@@ -27,7 +27,7 @@ pub fn main() {
             "-i",
             "./tailwind-entry.css",
             "-o",
-            &root_style_ui_path.to_str().unwrap(),
+            root_style_ui_path.to_str().unwrap(),
         ])
         .status()
         .expect("unable to build lumx styles");
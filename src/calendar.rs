@@ -0,0 +1,551 @@
+//! Month grids, week numbers and locale aware date entry, shared by the date inputs.
+
+use std::rc::Rc;
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+/// Order of the day, month and year when a date is typed or shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// `10/19/2026`
+    Mdy,
+    /// `19/10/2026`
+    Dmy,
+    /// `2026-10-19`
+    Ymd,
+}
+
+/// Names, first day of the week and date format used by the calendars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarLocale {
+    pub first_day: Weekday,
+    pub months: [&'static str; 12],
+    /// short names, from Monday to Sunday
+    pub weekdays: [&'static str; 7],
+    pub order: DateOrder,
+    pub separator: char,
+}
+
+impl Default for CalendarLocale {
+    fn default() -> Self {
+        Self::en()
+    }
+}
+
+impl CalendarLocale {
+    /// US English, weeks start on Sunday, `10/19/2026`
+    pub fn en() -> Self {
+        Self {
+            first_day: Weekday::Sun,
+            months: [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            weekdays: ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+            order: DateOrder::Mdy,
+            separator: '/',
+        }
+    }
+
+    /// British English, weeks start on Monday, `19/10/2026`
+    pub fn en_gb() -> Self {
+        Self {
+            first_day: Weekday::Mon,
+            order: DateOrder::Dmy,
+            ..Self::en()
+        }
+    }
+
+    /// German, weeks start on Monday, `19.10.2026`
+    pub fn de() -> Self {
+        Self {
+            first_day: Weekday::Mon,
+            months: [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            weekdays: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+            order: DateOrder::Dmy,
+            separator: '.',
+        }
+    }
+
+    /// French, weeks start on Monday, `19/10/2026`
+    pub fn fr() -> Self {
+        Self {
+            first_day: Weekday::Mon,
+            months: [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            weekdays: ["lu", "ma", "me", "je", "ve", "sa", "di"],
+            order: DateOrder::Dmy,
+            separator: '/',
+        }
+    }
+
+    pub fn with_first_day(mut self, first_day: Weekday) -> Self {
+        self.first_day = first_day;
+        self
+    }
+
+    pub fn month_name(&self, month: u32) -> &'static str {
+        self.months[(month as usize + 11) % 12]
+    }
+
+    pub fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        self.weekdays[weekday.num_days_from_monday() as usize]
+    }
+
+    /// Weekdays in the order of the grid columns.
+    pub fn ordered_weekdays(&self) -> [Weekday; 7] {
+        let mut weekday = self.first_day;
+
+        [(); 7].map(|_| {
+            let current = weekday;
+            weekday = weekday.succ();
+            current
+        })
+    }
+
+    /// e.g. `October 2026`
+    pub fn month_title(&self, date: NaiveDate) -> String {
+        format!("{} {}", self.month_name(date.month()), date.year())
+    }
+
+    pub fn format(&self, date: NaiveDate) -> String {
+        let sep = self.separator;
+        let (year, month, day) = (date.year(), date.month(), date.day());
+
+        match self.order {
+            DateOrder::Mdy => format!("{month:02}{sep}{day:02}{sep}{year:04}"),
+            DateOrder::Dmy => format!("{day:02}{sep}{month:02}{sep}{year:04}"),
+            DateOrder::Ymd => format!("{year:04}{sep}{month:02}{sep}{day:02}"),
+        }
+    }
+
+    /// Pattern hinting the expected entry, e.g. `mm/dd/yyyy`.
+    pub fn pattern(&self) -> String {
+        let sep = self.separator;
+
+        match self.order {
+            DateOrder::Mdy => format!("mm{sep}dd{sep}yyyy"),
+            DateOrder::Dmy => format!("dd{sep}mm{sep}yyyy"),
+            DateOrder::Ymd => format!("yyyy{sep}mm{sep}dd"),
+        }
+    }
+
+    /// Parses a typed date in the locale order, any separator is accepted
+    /// and ISO `yyyy-mm-dd` dates are always understood.
+    pub fn parse(&self, value: &str) -> Option<NaiveDate> {
+        let parts: Vec<&str> = value
+            .trim()
+            .split(|ch: char| !ch.is_ascii_digit())
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        let [first, second, third] = parts.as_slice() else {
+            return None;
+        };
+
+        let order = match first.len() {
+            4 => DateOrder::Ymd,
+            _ => self.order,
+        };
+
+        let (year, month, day) = match order {
+            DateOrder::Mdy => (third, first, second),
+            DateOrder::Dmy => (third, second, first),
+            DateOrder::Ymd => (first, second, third),
+        };
+
+        if year.len() != 4 {
+            return None;
+        }
+
+        NaiveDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+}
+
+/// One row of a month grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarWeek {
+    pub number: u32,
+    /// includes the days of the previous and next months filling the row
+    pub days: [NaiveDate; 7],
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Moves by a number of months, keeping the day within the target month.
+pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let moved = match months < 0 {
+        true => date.checked_sub_months(Months::new(months.unsigned_abs())),
+        false => date.checked_add_months(Months::new(months as u32)),
+    };
+
+    moved.unwrap_or(date)
+}
+
+pub fn add_days(date: NaiveDate, days: i64) -> NaiveDate {
+    let moved = match days < 0 {
+        true => date.checked_sub_days(Days::new(days.unsigned_abs())),
+        false => date.checked_add_days(Days::new(days as u64)),
+    };
+
+    moved.unwrap_or(date)
+}
+
+/// First day of the week holding the date.
+pub fn start_of_week(date: NaiveDate, first_day: Weekday) -> NaiveDate {
+    let offset = (date.weekday().num_days_from_monday() + 7 - first_day.num_days_from_monday()) % 7;
+
+    add_days(date, -(offset as i64))
+}
+
+/// Number of the week starting on the given day.
+///
+/// Weeks starting on Monday follow ISO 8601, the others count the week holding
+/// January 1st as the first one, as done in the US.
+pub fn week_number(week_start: NaiveDate, first_day: Weekday) -> u32 {
+    if first_day == Weekday::Mon {
+        return week_start.iso_week().week();
+    }
+
+    // a week spanning two years belongs to the new one
+    let week_end = add_days(week_start, 6);
+    let year_start = NaiveDate::from_ymd_opt(week_end.year(), 1, 1).unwrap_or(week_end);
+    let first_week = start_of_week(year_start, first_day);
+
+    ((week_start - first_week).num_days() / 7) as u32 + 1
+}
+
+/// Rows of the month holding the date, from 4 to 6 depending on the month.
+pub fn month_grid(date: NaiveDate, first_day: Weekday) -> Vec<CalendarWeek> {
+    let first = first_of_month(date);
+    let last = add_days(first, days_in_month(first.year(), first.month()) as i64 - 1);
+    let mut week_start = start_of_week(first, first_day);
+    let mut weeks = Vec::new();
+
+    while week_start <= last {
+        weeks.push(CalendarWeek {
+            number: week_number(week_start, first_day),
+            days: [0, 1, 2, 3, 4, 5, 6].map(|offset| add_days(week_start, offset)),
+        });
+
+        week_start = add_days(week_start, 7);
+    }
+
+    weeks
+}
+
+/// Keeps a date within the optional bounds.
+pub fn clamp_date(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> NaiveDate {
+    let date = min.map_or(date, |min| date.max(min));
+
+    max.map_or(date, |max| date.min(max))
+}
+
+/// Day focused by a key in the grid, following the WAI-ARIA date picker dialog pattern.
+pub fn key_target(
+    key: &str,
+    shift: bool,
+    focused: NaiveDate,
+    first_day: Weekday,
+) -> Option<NaiveDate> {
+    let target = match key {
+        "ArrowLeft" => add_days(focused, -1),
+        "ArrowRight" => add_days(focused, 1),
+        "ArrowUp" => add_days(focused, -7),
+        "ArrowDown" => add_days(focused, 7),
+        "Home" => start_of_week(focused, first_day),
+        "End" => add_days(start_of_week(focused, first_day), 6),
+        "PageUp" if shift => add_months(focused, -12),
+        "PageDown" if shift => add_months(focused, 12),
+        "PageUp" => add_months(focused, -1),
+        "PageDown" => add_months(focused, 1),
+        _ => return None,
+    };
+
+    Some(target)
+}
+
+/// The two days in chronological order.
+pub fn ordered_range(first: NaiveDate, second: NaiveDate) -> (NaiveDate, NaiveDate) {
+    (first.min(second), first.max(second))
+}
+
+pub fn first_of_quarter(date: NaiveDate) -> NaiveDate {
+    let month = (date.month0() / 3) * 3 + 1;
+
+    NaiveDate::from_ymd_opt(date.year(), month, 1).unwrap_or(date)
+}
+
+/// Named period offered as a shortcut by the range inputs, e.g. "Last 7 days".
+#[derive(Clone)]
+pub struct DateRangePreset {
+    pub label: String,
+    range: Rc<dyn Fn(NaiveDate) -> (NaiveDate, NaiveDate)>,
+}
+
+impl DateRangePreset {
+    /// `range` gives the period from the current day.
+    pub fn new(
+        label: impl Into<String>,
+        range: impl Fn(NaiveDate) -> (NaiveDate, NaiveDate) + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            range: Rc::new(range),
+        }
+    }
+
+    pub fn range(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        (self.range)(today)
+    }
+
+    pub fn today() -> Self {
+        Self::new("Today", |today| (today, today))
+    }
+
+    /// the given number of days, ending today
+    pub fn last_days(days: u32) -> Self {
+        Self::new(format!("Last {days} days"), move |today| {
+            (add_days(today, 1 - days.max(1) as i64), today)
+        })
+    }
+
+    pub fn this_month() -> Self {
+        Self::new("This month", |today| {
+            let first = first_of_month(today);
+
+            (first, add_days(add_months(first, 1), -1))
+        })
+    }
+
+    pub fn last_month() -> Self {
+        Self::new("Last month", |today| {
+            let first = first_of_month(today);
+
+            (add_months(first, -1), add_days(first, -1))
+        })
+    }
+
+    pub fn this_quarter() -> Self {
+        Self::new("This quarter", |today| {
+            let first = first_of_quarter(today);
+
+            (first, add_days(add_months(first, 3), -1))
+        })
+    }
+
+    pub fn this_year() -> Self {
+        Self::new("This year", |today| {
+            let first = NaiveDate::from_ymd_opt(today.year(), 1, 1).unwrap_or(today);
+
+            (first, add_days(add_months(first, 12), -1))
+        })
+    }
+
+    /// today, the last 7 and 30 days, this and last month, this quarter and this year
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::today(),
+            Self::last_days(7),
+            Self::last_days(30),
+            Self::this_month(),
+            Self::last_month(),
+            Self::this_quarter(),
+            Self::this_year(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, Weekday};
+
+    use crate::calendar::{
+        add_months, days_in_month, is_leap_year, key_target, month_grid, ordered_range,
+        week_number, CalendarLocale, DateRangePreset,
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn it_knows_leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2026));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2026, 2), 28);
+        assert_eq!(days_in_month(2026, 4), 30);
+    }
+
+    #[test]
+    fn it_moves_by_months_within_the_month() {
+        assert_eq!(add_months(date(2024, 1, 31), 1), date(2024, 2, 29));
+        assert_eq!(add_months(date(2024, 3, 31), -1), date(2024, 2, 29));
+        assert_eq!(add_months(date(2024, 2, 29), 12), date(2025, 2, 28));
+    }
+
+    #[test]
+    fn it_builds_the_month_grid() {
+        // October 2026 starts on a Thursday
+        let sunday_first = month_grid(date(2026, 10, 19), Weekday::Sun);
+        assert_eq!(sunday_first.len(), 5);
+        assert_eq!(sunday_first[0].days[0], date(2026, 9, 27));
+        assert_eq!(sunday_first[4].days[6], date(2026, 10, 31));
+
+        let monday_first = month_grid(date(2026, 10, 19), Weekday::Mon);
+        assert_eq!(monday_first.len(), 5);
+        assert_eq!(monday_first[0].days[0], date(2026, 9, 28));
+        assert_eq!(monday_first[4].days[6], date(2026, 11, 1));
+
+        // February 2026 starts on a Sunday and fits 4 rows
+        assert_eq!(month_grid(date(2026, 2, 1), Weekday::Sun).len(), 4);
+        // August 2026 starts on a Saturday and needs 6 rows
+        assert_eq!(month_grid(date(2026, 8, 1), Weekday::Sun).len(), 6);
+    }
+
+    #[test]
+    fn it_numbers_the_weeks() {
+        // ISO: 2026-01-01 is a Thursday, its week is the first one
+        assert_eq!(week_number(date(2025, 12, 29), Weekday::Mon), 1);
+        // ISO: 2021-01-01 is a Friday, its week belongs to 2020
+        assert_eq!(week_number(date(2020, 12, 28), Weekday::Mon), 53);
+        assert_eq!(week_number(date(2026, 10, 19), Weekday::Mon), 43);
+        // US: the week holding January 1st is the first one
+        assert_eq!(week_number(date(2020, 12, 27), Weekday::Sun), 1);
+        assert_eq!(week_number(date(2026, 10, 18), Weekday::Sun), 43);
+    }
+
+    #[test]
+    fn it_formats_and_parses_with_the_locale() {
+        let day = date(2026, 10, 9);
+
+        assert_eq!(CalendarLocale::en().format(day), "10/09/2026");
+        assert_eq!(CalendarLocale::de().format(day), "09.10.2026");
+        assert_eq!(CalendarLocale::en().parse("10/9/2026"), Some(day));
+        assert_eq!(CalendarLocale::fr().parse("9/10/2026"), Some(day));
+        assert_eq!(CalendarLocale::de().parse("09.10.2026"), Some(day));
+        assert_eq!(CalendarLocale::de().parse("2026-10-09"), Some(day));
+        assert_eq!(CalendarLocale::en().parse("02/30/2026"), None);
+        assert_eq!(CalendarLocale::en().parse("10/09/26"), None);
+        assert_eq!(CalendarLocale::en().parse("10/09"), None);
+        assert_eq!(CalendarLocale::en().pattern(), "mm/dd/yyyy");
+    }
+
+    #[test]
+    fn it_orders_the_weekdays() {
+        let locale = CalendarLocale::de();
+
+        assert_eq!(locale.ordered_weekdays()[0], Weekday::Mon);
+        assert_eq!(locale.weekday_name(Weekday::Sun), "So");
+        assert_eq!(
+            CalendarLocale::en().ordered_weekdays()[1..3],
+            [Weekday::Mon, Weekday::Tue]
+        );
+    }
+
+    #[test]
+    fn it_moves_the_focused_day_with_keys() {
+        let focused = date(2026, 10, 19);
+
+        assert_eq!(
+            key_target("ArrowUp", false, focused, Weekday::Mon),
+            Some(date(2026, 10, 12))
+        );
+        assert_eq!(
+            key_target("Home", false, focused, Weekday::Sun),
+            Some(date(2026, 10, 18))
+        );
+        assert_eq!(
+            key_target("End", false, focused, Weekday::Mon),
+            Some(date(2026, 10, 25))
+        );
+        assert_eq!(
+            key_target("PageDown", false, focused, Weekday::Mon),
+            Some(date(2026, 11, 19))
+        );
+        assert_eq!(
+            key_target("PageUp", true, focused, Weekday::Mon),
+            Some(date(2025, 10, 19))
+        );
+        assert_eq!(key_target("Enter", false, focused, Weekday::Mon), None);
+    }
+
+    #[test]
+    fn it_resolves_the_presets() {
+        let today = date(2026, 10, 19);
+
+        assert_eq!(
+            DateRangePreset::last_days(7).range(today),
+            (date(2026, 10, 13), today)
+        );
+        assert_eq!(
+            DateRangePreset::last_month().range(today),
+            (date(2026, 9, 1), date(2026, 9, 30))
+        );
+        assert_eq!(
+            DateRangePreset::this_quarter().range(today),
+            (date(2026, 10, 1), date(2026, 12, 31))
+        );
+        assert_eq!(
+            DateRangePreset::this_month().range(date(2028, 2, 10)),
+            (date(2028, 2, 1), date(2028, 2, 29))
+        );
+        assert_eq!(
+            ordered_range(today, date(2026, 10, 2)),
+            (date(2026, 10, 2), today)
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_effect, create_memo, create_node_ref, create_rw_signal, event_target_checked,
+    html::Input, view, Callback, CollectView, IntoView, MaybeSignal, Show, SignalGet,
+    SignalGetUntracked, SignalSet, SignalWith,
+};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    icons::{check::Check, minus::Minus},
+    id::resolve_id,
+    multi_select::toggle_selected,
+    select::SelectOption,
+};
+
+const BOX_STYLE_CLASS: &str =
+    "lumx-checkbox-box peer appearance-none w-4 h-4 shrink-0 rounded border bg-white cursor-pointer outline-none focus-visible:ring-2 focus-visible:ring-blue-600/40 disabled:opacity-50 disabled:cursor-not-allowed";
+
+const MARK_STYLE_CLASS: &str =
+    "lumx-checkbox-mark pointer-events-none absolute inset-0 w-4 h-4 text-white";
+
+/// Checkbox box specific properties by state.
+fn box_class(marked: bool, invalid: bool) -> String {
+    match (marked, invalid) {
+        (true, _) => format!("{BOX_STYLE_CLASS} bg-blue-600 border-blue-600"),
+        (false, true) => format!("{BOX_STYLE_CLASS} lumx-invalid border-red-600"),
+        (false, false) => format!("{BOX_STYLE_CLASS} border-slate-300"),
+    }
+}
+
+/// Single checkbox bound to a boolean control, an empty control is unchecked.
+///
+/// Use the `RequiredTrue` validator when the box must be checked, e.g. to accept terms.
+#[component]
+pub fn Checkbox(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    /// shows the box as partially checked until the user toggles it
+    #[prop(optional, into)]
+    indeterminate: MaybeSignal<bool>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<bool>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "checkbox");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let input_ref = create_node_ref::<Input>();
+    let control_value = control.value;
+
+    let checked = move || control_value.get().unwrap_or_default();
+    let is_indeterminate = create_rw_signal(false);
+
+    // follow the requested state, the user clears it by toggling the box
+    create_effect(move |_| is_indeterminate.set(indeterminate.get()));
+
+    // the indeterminate state only exists as an element property
+    create_effect(move |_| {
+        let is_indeterminate = is_indeterminate.get();
+
+        if let Some(input) = input_ref.get() {
+            input.set_indeterminate(is_indeterminate);
+        }
+    });
+
+    let on_change = {
+        let control_ref = Arc::clone(&control);
+
+        move |ev| {
+            is_indeterminate.set(false);
+            control_ref.mark_as_dirty();
+            control_ref.set_value(Some(event_target_checked(&ev)));
+        }
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // the feedback region only describes the checkbox while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    view! {
+        <div class="lumx-checkbox flex flex-col mb-2.5">
+            <label for=form_id.clone() class="lumx-checkbox-label inline-flex items-center gap-x-2 text-sm text-slate-900 cursor-pointer">
+                <span class="relative inline-flex">
+                    <input
+                        node_ref=input_ref
+                        type="checkbox"
+                        id=form_id
+                        class=move || box_class(checked() || is_indeterminate.get(), visible_err.with(Option::is_some))
+                        prop:checked=checked
+                        aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                        aria-describedby=described_by
+                        on:change=on_change
+                        on:blur=on_blur />
+
+                    <Show
+                        when=move || is_indeterminate.get()
+                        fallback=move || view! {
+                            <Show when=checked>
+                                <Check class=MARK_STYLE_CLASS />
+                            </Show>
+                        }>
+                        <Minus class=MARK_STYLE_CLASS />
+                    </Show>
+                </span>
+                {label}
+            </label>
+
+            <div class="pl-6">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+        </div>
+    }
+}
+
+/// Several checkboxes bound to the list of checked values.
+#[component]
+pub fn CheckboxGroup<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    #[prop(into)] options: MaybeSignal<Vec<SelectOption<T>>>,
+    control: Arc<FormControl<Vec<T>>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let form_id = resolve_id(id, "checkbox-group");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let control_value = control.value;
+
+    let options = create_memo(move |_| options.get());
+
+    let is_checked = move |value: &T| {
+        control_value.with(|selected| {
+            selected
+                .as_ref()
+                .is_some_and(|selected| selected.contains(value))
+        })
+    };
+
+    let toggle = {
+        let control_ref = Arc::clone(&control);
+
+        move |value: &T| {
+            let selected = toggle_selected(
+                &control_value.get_untracked().unwrap_or_default(),
+                value,
+                None,
+            );
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value((!selected.is_empty()).then_some(selected));
+        }
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // the feedback region only describes the group while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let render_options = move || {
+        options
+            .get()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, option)| {
+                let option_id = format!("{form_id}-option-{idx}");
+                let checked = {
+                    let value = option.value.clone();
+
+                    move || is_checked(&value)
+                };
+                let on_change = {
+                    let toggle = toggle.clone();
+                    let value = option.value.clone();
+
+                    move |_ev| toggle(&value)
+                };
+
+                view! {
+                    <label for=option_id.clone() class="lumx-checkbox-label inline-flex items-center gap-x-2 text-sm text-slate-900 cursor-pointer">
+                        <span class="relative inline-flex">
+                            <input
+                                type="checkbox"
+                                id=option_id
+                                class={
+                                    let checked = checked.clone();
+                                    move || box_class(checked(), visible_err.with(Option::is_some))
+                                }
+                                disabled=option.disabled
+                                prop:checked=checked.clone()
+                                on:change=on_change
+                                on:blur=on_blur.clone() />
+
+                            <Show when=checked.clone()>
+                                <Check class=MARK_STYLE_CLASS />
+                            </Show>
+                        </span>
+                        {option.label}
+                    </label>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <fieldset
+            class="lumx-checkbox-group flex flex-col gap-y-2 mb-2.5"
+            aria-invalid=move || visible_err.with(Option::is_some).to_string()
+            aria-describedby=described_by>
+            <legend class="text-xs text-slate-900 mb-1">{label}</legend>
+
+            {render_options}
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </fieldset>
+    }
+}
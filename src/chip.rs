@@ -0,0 +1,40 @@
+use leptos::{component, ev::MouseEvent, view, Callable, Callback, IntoView};
+
+use crate::icons::x_mark::XMark;
+
+#[component]
+pub fn Chip(
+    #[prop(into)] label: String,
+    /// shows a remove button when set
+    #[prop(optional, into)]
+    on_remove: Option<Callback<()>>,
+    /// marks the chip as holding a value that failed validation
+    #[prop(optional)]
+    invalid: bool,
+) -> impl IntoView {
+    let class_props_by_state = match invalid {
+        true => "lumx-chip lumx-invalid inline-flex items-center gap-x-1 rounded-full border border-red-600 bg-red-50 pl-2 pr-1 py-0.5 text-xs text-red-700",
+        false => "lumx-chip inline-flex items-center gap-x-1 rounded-full border border-slate-200 bg-slate-100 pl-2 pr-1 py-0.5 text-xs text-slate-900",
+    };
+
+    let remove_label = format!("remove {label}");
+
+    view! {
+        <span class=class_props_by_state>
+            <span class="lumx-chip-label">{label}</span>
+            {on_remove.map(|on_remove| view! {
+                <button type="button"
+                    tabindex="-1"
+                    aria-label=remove_label
+                    on:click=move |ev: MouseEvent| {
+                        // keep the click from reaching the field and opening panels
+                        ev.stop_propagation();
+                        on_remove.call(());
+                    }
+                    class="lumx-chip-remove cursor-pointer rounded-full hover:bg-slate-200">
+                    <XMark class="w-3.5 h-3.5" />
+                </button>
+            })}
+        </span>
+    }
+}
@@ -0,0 +1,498 @@
+use std::sync::Arc;
+
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+use leptos::{
+    component, create_effect, create_memo, create_node_ref, create_rw_signal, create_trigger,
+    ev::{KeyboardEvent, MouseEvent},
+    event_target_value,
+    html::{Button, Div, Input, Td},
+    use_context, view, watch, Callable, Callback, CollectView, IntoView, RwSignal, Show, Signal,
+    SignalGet, SignalGetUntracked, SignalSet, SignalWith, Trigger,
+};
+
+use crate::{
+    calendar::{add_months, clamp_date, first_of_month, key_target, month_grid, CalendarLocale},
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    icons::{
+        calendar_days::CalendarDays, chevron_double_left::ChevronDoubleLeft,
+        chevron_double_right::ChevronDoubleRight, chevron_left::ChevronLeft,
+        chevron_right::ChevronRight,
+    },
+    id::resolve_id,
+    popover::{use_popover, Popover},
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-date-picker flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+const NAV_STYLE_CLASS: &str =
+    "lumx-calendar-nav shrink-0 cursor-pointer rounded-full p-1 text-slate-900 hover:bg-slate-200 disabled:opacity-40 disabled:cursor-not-allowed";
+
+/// Month grid to pick a day, used by the date inputs.
+///
+/// Follows the WAI-ARIA date picker dialog pattern: the focused day is the only tab stop of
+/// the grid, the arrow keys move by days and weeks, `PageUp` and `PageDown` by months and,
+/// holding `Shift`, by years.
+#[component]
+pub fn Calendar(
+    /// prefix of the ids of the days
+    #[prop(into)]
+    id: String,
+    /// day holding the focus, its month is the one shown
+    focused: RwSignal<NaiveDate>,
+    #[prop(into)] selected: Signal<Option<NaiveDate>>,
+    #[prop(default = None)] min: Option<NaiveDate>,
+    #[prop(default = None)] max: Option<NaiveDate>,
+    /// days that cannot be picked besides the ones out of bounds
+    #[prop(default = None)]
+    is_date_disabled: Option<Callback<NaiveDate, bool>>,
+    #[prop(optional)] locale: CalendarLocale,
+    #[prop(optional)] show_week_numbers: bool,
+    /// period highlighted from its first to its last day, both included
+    #[prop(default = None, into)]
+    range: Option<Signal<Option<(NaiveDate, NaiveDate)>>>,
+    /// shows the buttons to the previous month and year
+    #[prop(default = true)]
+    show_prev: bool,
+    /// shows the buttons to the next month and year
+    #[prop(default = true)]
+    show_next: bool,
+    /// moves the focus to the focused day when notified, e.g. once the calendar opens
+    #[prop(optional)]
+    focus: Option<Trigger>,
+    #[prop(into)] on_select: Callback<NaiveDate>,
+    #[prop(optional, into)] on_escape: Option<Callback<()>>,
+    /// day pointed at with the mouse or moved to with the keyboard
+    #[prop(optional, into)]
+    on_hover: Option<Callback<NaiveDate>>,
+) -> impl IntoView {
+    let title_id = format!("{id}-title");
+    let focus = focus.unwrap_or_else(create_trigger);
+    let today = Local::now().date_naive();
+    let month = create_memo(move |_| first_of_month(focused.get()));
+
+    let is_disabled = move |date: NaiveDate| {
+        clamp_date(date, min, max) != date
+            || is_date_disabled.is_some_and(|is_date_disabled| is_date_disabled.call(date))
+    };
+
+    let move_focus = move |date: NaiveDate| {
+        let date = clamp_date(date, min, max);
+
+        focused.set(date);
+        focus.notify();
+
+        if let Some(on_hover) = on_hover {
+            on_hover.call(date);
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let key = ev.key();
+        let current = focused.get_untracked();
+
+        match key.as_str() {
+            "Enter" | " " => {
+                if !is_disabled(current) {
+                    on_select.call(current);
+                }
+            }
+            "Escape" => match on_escape {
+                Some(on_escape) => on_escape.call(()),
+                None => return,
+            },
+            _ => match key_target(&key, ev.shift_key(), current, locale.first_day) {
+                Some(target) => move_focus(target),
+                None => return,
+            },
+        }
+
+        ev.prevent_default();
+    };
+
+    // month navigation keeps the focus on the pressed button
+    let nav = move |months: i32| {
+        move |_ev: MouseEvent| {
+            focused.set(clamp_date(
+                add_months(focused.get_untracked(), months),
+                min,
+                max,
+            ))
+        }
+    };
+
+    let can_go_back = move || min.is_none_or(|min| month.get() > first_of_month(min));
+    let can_go_forward = move || max.is_none_or(|max| month.get() < first_of_month(max));
+
+    let render_day = {
+        let id = id.clone();
+
+        move |date: NaiveDate| {
+            let day_ref = create_node_ref::<Td>();
+            let in_month = date.month() == month.get_untracked().month();
+            let disabled = is_disabled(date);
+            let is_focused = move || focused.get() == date;
+            let is_endpoint = move || {
+                selected.get() == Some(date)
+                    || range.is_some_and(|range| {
+                        range.with(|range| {
+                            range.is_some_and(|(first, last)| date == first || date == last)
+                        })
+                    })
+            };
+            let is_within = move || {
+                range.is_some_and(|range| {
+                    range.with(|range| {
+                        range.is_some_and(|(first, last)| first < date && date < last)
+                    })
+                })
+            };
+            let is_selected = move || is_endpoint() || is_within();
+
+            let _ = watch(
+                move || focus.track(),
+                move |_, _, _| {
+                    if focused.get_untracked() == date {
+                        if let Some(day) = day_ref.get_untracked() {
+                            let _ = day.focus();
+                        }
+                    }
+                },
+                false,
+            );
+
+            let day_class = move || {
+                let state = match (is_endpoint(), is_within(), disabled, in_month) {
+                    (true, _, _, _) => "bg-blue-600 text-white",
+                    (false, true, _, _) => "bg-blue-100 text-slate-900",
+                    (false, false, true, _) => "text-slate-300 line-through cursor-not-allowed",
+                    (false, false, false, true) => "text-slate-900 hover:bg-slate-200",
+                    (false, false, false, false) => "text-slate-400 hover:bg-slate-200",
+                };
+
+                let today_class = match date == today {
+                    true => "font-semibold underline",
+                    false => "",
+                };
+
+                format!("lumx-calendar-day w-8 h-8 text-center text-sm rounded-full cursor-pointer outline-none focus-visible:ring-2 focus-visible:ring-blue-600/40 {state} {today_class}")
+            };
+
+            view! {
+                <td node_ref=day_ref
+                    id=format!("{id}-{date}")
+                    tabindex=move || if is_focused() { "0" } else { "-1" }
+                    aria-selected=move || is_selected().to_string()
+                    aria-disabled=disabled.then_some("true")
+                    aria-current=(date == today).then_some("date")
+                    aria-label=format!("{} {} {}", date.day(), locale.month_name(date.month()), date.year())
+                    class=day_class
+                    on:mouseenter=move |_ev: MouseEvent| {
+                        if let Some(on_hover) = on_hover {
+                            on_hover.call(date);
+                        }
+                    }
+                    on:click=move |_ev: MouseEvent| {
+                        if !disabled {
+                            focused.set(date);
+                            on_select.call(date);
+                        }
+                    }>
+                    {date.day()}
+                </td>
+            }
+        }
+    };
+
+    let render_weeks = move || {
+        month_grid(month.get(), locale.first_day)
+            .into_iter()
+            .map(|week| {
+                let render_day = render_day.clone();
+
+                view! {
+                    <tr>
+                        <Show when=move || show_week_numbers>
+                            <th scope="row" class="lumx-calendar-week w-8 text-center text-xs font-normal text-slate-400">
+                                {week.number}
+                            </th>
+                        </Show>
+                        {week.days.into_iter().map(render_day).collect_view()}
+                    </tr>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class="lumx-calendar flex flex-col gap-y-2 p-2">
+            <div class="flex flex-row items-center gap-x-1">
+                <Show when=move || show_prev>
+                    <button type="button"
+                        aria-label="previous year"
+                        disabled=move || !can_go_back()
+                        on:click=nav(-12)
+                        class=NAV_STYLE_CLASS>
+                        <ChevronDoubleLeft class="w-4 h-4" />
+                    </button>
+                    <button type="button"
+                        aria-label="previous month"
+                        disabled=move || !can_go_back()
+                        on:click=nav(-1)
+                        class=NAV_STYLE_CLASS>
+                        <ChevronLeft class="w-4 h-4" />
+                    </button>
+                </Show>
+
+                <div id=title_id.clone() aria-live="polite" class="lumx-calendar-title grow text-center text-sm font-semibold text-slate-900">
+                    {move || locale.month_title(month.get())}
+                </div>
+
+                <Show when=move || show_next>
+                    <button type="button"
+                        aria-label="next month"
+                        disabled=move || !can_go_forward()
+                        on:click=nav(1)
+                        class=NAV_STYLE_CLASS>
+                        <ChevronRight class="w-4 h-4" />
+                    </button>
+                    <button type="button"
+                        aria-label="next year"
+                        disabled=move || !can_go_forward()
+                        on:click=nav(12)
+                        class=NAV_STYLE_CLASS>
+                        <ChevronDoubleRight class="w-4 h-4" />
+                    </button>
+                </Show>
+            </div>
+
+            <table role="grid" aria-labelledby=title_id class="lumx-calendar-grid border-collapse" on:keydown=on_keydown>
+                <thead>
+                    <tr>
+                        <Show when=move || show_week_numbers>
+                            <th scope="col" aria-label="week" class="w-8 text-xs font-normal text-slate-400">"#"</th>
+                        </Show>
+                        {locale
+                            .ordered_weekdays()
+                            .map(|weekday| view! {
+                                <th scope="col" class="w-8 text-xs font-normal text-slate-500">
+                                    {locale.weekday_name(weekday)}
+                                </th>
+                            })
+                            .collect_view()}
+                    </tr>
+                </thead>
+                <tbody>
+                    {render_weeks}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+/// Date field with a calendar popup, bound to a date control.
+///
+/// Dates can be typed in the locale format. Like the number inputs, typed dates out of
+/// `min` and `max` are kept so validators like `MinDate` can report them.
+#[component]
+pub fn DatePicker(
+    #[prop(default = "label")] label: &'static str,
+    /// defaults to the locale pattern, e.g. `mm/dd/yyyy`
+    #[prop(optional)]
+    placeholder: Option<&'static str>,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] min: Option<NaiveDate>,
+    #[prop(optional)] max: Option<NaiveDate>,
+    /// days that cannot be picked in the calendar
+    #[prop(optional, into)]
+    is_date_disabled: Option<Callback<NaiveDate, bool>>,
+    /// names and format, defaults to a provided `CalendarLocale` or to `CalendarLocale::en`
+    #[prop(optional)]
+    locale: Option<CalendarLocale>,
+    /// overrides the first day of the week of the locale
+    #[prop(optional)]
+    first_day: Option<Weekday>,
+    #[prop(optional)] show_week_numbers: bool,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<NaiveDate>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "date-picker");
+    let dialog_id = format!("{form_id}-dialog");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let picker_ref = create_node_ref::<Div>();
+    let input_ref = create_node_ref::<Input>();
+    let toggle_ref = create_node_ref::<Button>();
+    let control_value = control.value;
+
+    let locale = locale
+        .or_else(use_context::<CalendarLocale>)
+        .unwrap_or_default();
+    let locale = first_day.map_or(locale, |first_day| locale.with_first_day(first_day));
+    let placeholder = placeholder.map_or_else(|| locale.pattern(), String::from);
+
+    let popover = use_popover(picker_ref, || {});
+    let focused = create_rw_signal(clamp_date(Local::now().date_naive(), min, max));
+    let focus_day = create_trigger();
+
+    let display_value =
+        move |value: Option<NaiveDate>| value.map(|value| locale.format(value)).unwrap_or_default();
+
+    let open_calendar = move || {
+        let start = control_value
+            .get_untracked()
+            .unwrap_or_else(|| Local::now().date_naive());
+
+        focused.set(clamp_date(start, min, max));
+        popover.open();
+        focus_day.notify();
+    };
+
+    let on_toggle = move |_ev: MouseEvent| match popover.is_open_untracked() {
+        true => popover.close(),
+        false => open_calendar(),
+    };
+
+    // notify the parsed date to the control while the user types
+    let on_input = {
+        let control_ref = Arc::clone(&control);
+
+        move |ev| {
+            let input_value = event_target_value(&ev);
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value(locale.parse(&input_value));
+        }
+    };
+
+    // show the typed date in the locale format once the field is left
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+
+            if let (Some(input), Some(value)) =
+                (input_ref.get_untracked(), control_value.get_untracked())
+            {
+                input.set_value(&display_value(Some(value)));
+            }
+        }
+    };
+
+    let on_input_keydown = move |ev: KeyboardEvent| {
+        if ev.key() == "ArrowDown" && ev.alt_key() {
+            ev.prevent_default();
+            open_calendar();
+        }
+    };
+
+    let on_select = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |date: NaiveDate| {
+            control_ref.mark_as_dirty();
+            control_ref.mark_as_touched();
+            control_ref.set_value(Some(date));
+            popover.close();
+
+            if let Some(input) = input_ref.get_untracked() {
+                let _ = input.focus();
+            }
+        }
+    });
+
+    let on_escape = Callback::new(move |_: ()| popover.dismiss(toggle_ref));
+
+    // reflect control changes into the element, only writing when the typed
+    // text does not already stand for the date so the caret is not moved.
+    create_effect(move |_| {
+        let value = control_value.get();
+
+        if let Some(input) = input_ref.get() {
+            if locale.parse(&input.value()) != value {
+                input.set_value(&display_value(value));
+            }
+        }
+    });
+
+    // field specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the input while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    view! {
+        <div node_ref=picker_ref class="lumx-date-picker-relative relative">
+            <div class=class_props_by_state>
+                <label for=form_id.clone() class="text-xs text-slate-900">{label}</label>
+                <div class="lumx-date-picker-field flex flex-row items-center gap-x-1">
+                    <input
+                        node_ref=input_ref
+                        class="grow min-w-0 outline-none text-sm bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
+                        type="text"
+                        inputmode="numeric"
+                        autocomplete="off"
+                        placeholder=placeholder
+                        id=form_id.clone()
+                        aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                        aria-describedby=described_by
+                        on:input=on_input
+                        on:blur=on_blur
+                        on:keydown=on_input_keydown
+                        value=display_value(control_value.get_untracked()) />
+
+                    <button type="button"
+                        node_ref=toggle_ref
+                        aria-label="choose date"
+                        aria-haspopup="dialog"
+                        aria-controls=dialog_id.clone()
+                        aria-expanded=move || popover.is_open().to_string()
+                        on:click=on_toggle
+                        class="lumx-date-picker-toggle shrink-0 cursor-pointer rounded-full p-0.5 text-slate-900 hover:bg-slate-200">
+                        <CalendarDays class="w-5 h-5" />
+                    </button>
+                </div>
+
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+
+            <Popover state=popover>
+                <div id=dialog_id.clone()
+                    role="dialog"
+                    aria-modal="false"
+                    aria-label="choose date"
+                    class="lumx-date-picker-dialog absolute z-10 -mt-1.5 rounded-md border box-border border-slate-200 bg-white">
+                    <Calendar
+                        id=format!("{form_id}-day")
+                        focused=focused
+                        selected=Signal::derive(move || control_value.get())
+                        min=min
+                        max=max
+                        is_date_disabled=is_date_disabled
+                        locale=locale
+                        show_week_numbers=show_week_numbers
+                        focus=focus_day
+                        on_select=on_select
+                        on_escape=on_escape />
+                </div>
+            </Popover>
+        </div>
+    }
+}
@@ -0,0 +1,401 @@
+use std::sync::Arc;
+
+use chrono::{Local, NaiveDate, Weekday};
+use leptos::{
+    component, create_effect, create_node_ref, create_rw_signal, create_trigger,
+    ev::{KeyboardEvent, MouseEvent},
+    event_target_value,
+    html::{Button, Div, Input},
+    use_context, view, watch, Callable, Callback, CollectView, IntoView, Signal, SignalGet,
+    SignalGetUntracked, SignalSet, SignalWith, Trigger,
+};
+
+use crate::{
+    calendar::{
+        add_months, clamp_date, first_of_month, ordered_range, CalendarLocale, DateRangePreset,
+    },
+    date_picker::Calendar,
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        date_range::DateRangeControl,
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    icons::calendar_days::CalendarDays,
+    id::resolve_id,
+    popover::{use_popover, Popover},
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-date-range-picker flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+/// One of the two typed dates of the range.
+#[component]
+fn RangeDateInput(
+    id: String,
+    aria_label: &'static str,
+    placeholder: String,
+    locale: CalendarLocale,
+    #[prop(into)] invalid: Signal<bool>,
+    #[prop(into)] described_by: Signal<Option<String>>,
+    /// moves the focus to the input when notified
+    #[prop(optional)]
+    focus: Option<Trigger>,
+    on_open: Callback<()>,
+    control: Arc<FormControl<NaiveDate>>,
+) -> impl IntoView {
+    let input_ref = create_node_ref::<Input>();
+    let control_value = control.value;
+
+    let display_value =
+        move |value: Option<NaiveDate>| value.map(|value| locale.format(value)).unwrap_or_default();
+
+    let _ = watch(
+        move || focus.map(|focus| focus.track()),
+        move |_, _, _| {
+            if let Some(input) = input_ref.get_untracked() {
+                let _ = input.focus();
+            }
+        },
+        false,
+    );
+
+    // notify the parsed date to the control while the user types
+    let on_input = {
+        let control_ref = Arc::clone(&control);
+
+        move |ev| {
+            let input_value = event_target_value(&ev);
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value(locale.parse(&input_value));
+        }
+    };
+
+    // show the typed date in the locale format once the field is left
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+
+            if let (Some(input), Some(value)) =
+                (input_ref.get_untracked(), control_value.get_untracked())
+            {
+                input.set_value(&display_value(Some(value)));
+            }
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        if ev.key() == "ArrowDown" && ev.alt_key() {
+            ev.prevent_default();
+            on_open.call(());
+        }
+    };
+
+    // reflect control changes into the element, only writing when the typed
+    // text does not already stand for the date so the caret is not moved.
+    create_effect(move |_| {
+        let value = control_value.get();
+
+        if let Some(input) = input_ref.get() {
+            if locale.parse(&input.value()) != value {
+                input.set_value(&display_value(value));
+            }
+        }
+    });
+
+    view! {
+        <input
+            node_ref=input_ref
+            class="w-full min-w-0 outline-none text-sm bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
+            type="text"
+            inputmode="numeric"
+            autocomplete="off"
+            placeholder=placeholder
+            id=id
+            aria-label=aria_label
+            aria-invalid=move || invalid.get().to_string()
+            aria-describedby=move || described_by.get()
+            on:input=on_input
+            on:blur=on_blur
+            on:keydown=on_keydown
+            value=display_value(control_value.get_untracked()) />
+    }
+}
+
+/// Period field with two months side by side and optional preset shortcuts, bound to a
+/// `DateRangeControl`.
+///
+/// The first picked day starts the range, the second one ends it, in either order, and the
+/// range follows the pointer in between. Typed dates are kept as they are, an end before the
+/// start is reported by the `DateRangeOrder` validator of the control.
+#[component]
+pub fn DateRangePicker(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] min: Option<NaiveDate>,
+    #[prop(optional)] max: Option<NaiveDate>,
+    /// days that cannot be picked in the calendars
+    #[prop(optional, into)]
+    is_date_disabled: Option<Callback<NaiveDate, bool>>,
+    /// names and format, defaults to a provided `CalendarLocale` or to `CalendarLocale::en`
+    #[prop(optional)]
+    locale: Option<CalendarLocale>,
+    /// overrides the first day of the week of the locale
+    #[prop(optional)]
+    first_day: Option<Weekday>,
+    #[prop(optional)] show_week_numbers: bool,
+    /// shortcuts shown next to the calendars, e.g. `DateRangePreset::defaults()`
+    #[prop(optional)]
+    presets: Vec<DateRangePreset>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: DateRangeControl,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "date-range-picker");
+    let label_id = format!("{form_id}-label");
+    let dialog_id = format!("{form_id}-dialog");
+    let feedback_id = format!("{form_id}-feedback");
+    let picker_ref = create_node_ref::<Div>();
+    let toggle_ref = create_node_ref::<Button>();
+    let (start_value, end_value) = (control.start.value, control.end.value);
+
+    let start_err = visible_err(Arc::clone(&control.start), err_message);
+    let end_err = visible_err(Arc::clone(&control.end), err_message);
+    let visible_err = Signal::derive(move || start_err.get().or_else(|| end_err.get()));
+    let invalid = Signal::derive(move || visible_err.with(Option::is_some));
+
+    let locale = locale
+        .or_else(use_context::<CalendarLocale>)
+        .unwrap_or_default();
+    let locale = first_day.map_or(locale, |first_day| locale.with_first_day(first_day));
+
+    // first picked day while the second one is awaited
+    let anchor = create_rw_signal(None::<NaiveDate>);
+    let hover = create_rw_signal(None::<NaiveDate>);
+    let popover = use_popover(picker_ref, move || {
+        anchor.set(None);
+        hover.set(None);
+    });
+    let today = Local::now().date_naive();
+    let left_focused = create_rw_signal(clamp_date(today, min, max));
+    let right_focused = create_rw_signal(add_months(first_of_month(today), 1));
+    let (focus_left, focus_start) = (create_trigger(), create_trigger());
+
+    // range being picked, or the one of the control
+    let preview = Signal::derive(move || match anchor.get() {
+        Some(anchor) => Some(ordered_range(anchor, hover.get().unwrap_or(anchor))),
+        None => start_value
+            .get()
+            .zip(end_value.get())
+            .filter(|(start, end)| start <= end),
+    });
+
+    let open_calendar = Callback::new(move |_: ()| {
+        let start = start_value.get_untracked().unwrap_or(today);
+
+        anchor.set(None);
+        hover.set(None);
+        left_focused.set(clamp_date(start, min, max));
+        popover.open();
+        focus_left.notify();
+    });
+
+    let on_toggle = move |_ev: MouseEvent| match popover.is_open_untracked() {
+        true => popover.close(),
+        false => open_calendar.call(()),
+    };
+
+    // the right calendar always shows the month after the left one
+    create_effect(move |_| {
+        let next = add_months(first_of_month(left_focused.get()), 1);
+
+        if first_of_month(right_focused.get_untracked()) != next {
+            right_focused.set(next);
+        }
+    });
+
+    create_effect(move |_| {
+        let right = right_focused.get();
+        let previous = add_months(first_of_month(right), -1);
+
+        if first_of_month(left_focused.get_untracked()) != previous {
+            left_focused.set(add_months(right, -1));
+        }
+    });
+
+    let apply_range = {
+        let control = control.clone();
+
+        move |range: (NaiveDate, NaiveDate)| {
+            control.mark_as_dirty();
+            control.mark_as_touched();
+            control.set_range(Some(range));
+            popover.close();
+            focus_start.notify();
+        }
+    };
+
+    let on_select = Callback::new({
+        let apply_range = apply_range.clone();
+
+        move |date: NaiveDate| match anchor.get_untracked() {
+            None => {
+                anchor.set(Some(date));
+                hover.set(Some(date));
+            }
+            Some(first) => apply_range(ordered_range(first, date)),
+        }
+    });
+
+    let on_hover = Callback::new(move |date: NaiveDate| {
+        if anchor.get_untracked().is_some() {
+            hover.set(Some(date));
+        }
+    });
+
+    let on_escape = Callback::new(move |_: ()| popover.dismiss(toggle_ref));
+
+    // field specific properties by validation state
+    let class_props_by_state = move || match invalid.get() {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the inputs while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        Signal::derive(move || (hint.is_some() || invalid.get()).then(|| feedback_id.clone()))
+    };
+
+    let has_presets = !presets.is_empty();
+
+    let render_presets = {
+        let control = control.clone();
+
+        move || {
+            presets
+                .iter()
+                .map(|preset| {
+                    let range = preset.range(today);
+                    let control = control.clone();
+                    let apply_range = apply_range.clone();
+                    let is_current = Signal::derive(move || control.range() == Some(range));
+
+                    view! {
+                        <li>
+                            <button type="button"
+                                aria-pressed=move || is_current.get().to_string()
+                                on:click=move |_ev: MouseEvent| apply_range(range)
+                                class="lumx-date-range-preset w-full text-left text-sm rounded-md px-2 py-1 cursor-pointer hover:bg-slate-200"
+                                class=("bg-blue-100", move || is_current.get())
+                                class=("text-slate-900", move || !is_current.get())>
+                                {preset.label.clone()}
+                            </button>
+                        </li>
+                    }
+                })
+                .collect_view()
+        }
+    };
+
+    view! {
+        <div node_ref=picker_ref class="lumx-date-range-picker-relative relative">
+            <div id=form_id.clone()
+                role="group"
+                aria-labelledby=label_id.clone()
+                class=class_props_by_state>
+                <div id=label_id class="text-xs text-slate-900">{label}</div>
+                <div class="lumx-date-range-picker-field flex flex-row items-center gap-x-1">
+                    <RangeDateInput
+                        id=format!("{form_id}-start")
+                        aria_label="start date"
+                        placeholder=locale.pattern()
+                        locale=locale
+                        invalid=Signal::derive(move || start_err.with(Option::is_some))
+                        described_by=described_by
+                        focus=focus_start
+                        on_open=open_calendar
+                        control=Arc::clone(&control.start) />
+
+                    <span aria-hidden="true" class="text-sm text-slate-400">"–"</span>
+
+                    <RangeDateInput
+                        id=format!("{form_id}-end")
+                        aria_label="end date"
+                        placeholder=locale.pattern()
+                        locale=locale
+                        invalid=Signal::derive(move || end_err.with(Option::is_some))
+                        described_by=described_by
+                        on_open=open_calendar
+                        control=Arc::clone(&control.end) />
+
+                    <button type="button"
+                        node_ref=toggle_ref
+                        aria-label="choose dates"
+                        aria-haspopup="dialog"
+                        aria-controls=dialog_id.clone()
+                        aria-expanded=move || popover.is_open().to_string()
+                        on:click=on_toggle
+                        class="lumx-date-range-picker-toggle shrink-0 cursor-pointer rounded-full p-0.5 text-slate-900 hover:bg-slate-200">
+                        <CalendarDays class="w-5 h-5" />
+                    </button>
+                </div>
+
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+
+            <Popover state=popover>
+                <div id=dialog_id.clone()
+                    role="dialog"
+                    aria-modal="false"
+                    aria-label="choose dates"
+                    class="lumx-date-range-picker-dialog absolute z-10 -mt-1.5 flex flex-row rounded-md border box-border border-slate-200 bg-white">
+                    {has_presets.then(|| view! {
+                        <ul aria-label="presets" class="lumx-date-range-presets flex flex-col gap-y-1 p-2 border-r border-slate-200">
+                            {render_presets()}
+                        </ul>
+                    })}
+
+                    <Calendar
+                        id=format!("{form_id}-day")
+                        focused=left_focused
+                        selected=Signal::derive(move || anchor.get())
+                        range=preview
+                        min=min
+                        max=max
+                        is_date_disabled=is_date_disabled
+                        locale=locale
+                        show_week_numbers=show_week_numbers
+                        show_next=false
+                        focus=focus_left
+                        on_select=on_select
+                        on_escape=on_escape
+                        on_hover=on_hover />
+
+                    <Calendar
+                        id=format!("{form_id}-next-day")
+                        focused=right_focused
+                        selected=Signal::derive(move || anchor.get())
+                        range=preview
+                        min=min
+                        max=max
+                        is_date_disabled=is_date_disabled
+                        locale=locale
+                        show_week_numbers=show_week_numbers
+                        show_prev=false
+                        on_select=on_select
+                        on_escape=on_escape
+                        on_hover=on_hover />
+                </div>
+            </Popover>
+        </div>
+    }
+}
@@ -1,6 +1,7 @@
 use leptos::{component, html::Dialog, view, Children, IntoView, NodeRef};
 
 #[component]
+#[allow(unused_variables)]
 pub fn Dialog(children: Children, dialog_ref: NodeRef<Dialog>) -> impl IntoView {
     view! {
         <dialog
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_memo, view, Callable, Callback, IntoView, Memo, Signal, SignalGet, SignalWith,
+};
+
+use crate::forms::{
+    control::{AbstractFormControl, FormControl},
+    feedback::{default_err_message, use_form_feedback},
+    validator::{ValidatorKey, ValidatorMetadata},
+};
+
+/// Message of the first error of the control, present only while the
+/// form wide visibility rule allows errors to be shown.
+pub fn visible_err<T>(
+    control: Arc<FormControl<T>>,
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+) -> Memo<Option<String>>
+where
+    T: Clone + 'static,
+{
+    let feedback = use_form_feedback();
+
+    create_memo(move |_| {
+        if !feedback.show_errs(control.as_ref()) {
+            return None;
+        }
+
+        control
+            .errs()
+            .into_iter()
+            .next()
+            .map(|(key, meta)| match err_message {
+                Some(err_message) => err_message.call((key, meta)),
+                None => default_err_message(&key, &meta),
+            })
+    })
+}
+
+/// Hint or error text rendered below a field, referenced by its `aria-describedby`.
+#[component]
+pub fn FieldFeedback(
+    id: String,
+    #[prop(default = None)] hint: Option<&'static str>,
+    #[prop(into)] err: Signal<Option<String>>,
+) -> impl IntoView {
+    let class = move || match err.with(Option::is_some) {
+        true => "lumx-field-feedback text-xs text-red-600",
+        false => "lumx-field-feedback text-xs text-slate-500",
+    };
+
+    view! {
+        <div id=id class=class aria-live="polite">
+            {move || err.get().or(hint.map(String::from))}
+        </div>
+    }
+}
@@ -0,0 +1,158 @@
+//! Descriptors of uploaded files and the upload callback of the file inputs.
+
+use std::{future::Future, pin::Pin, rc::Rc};
+
+use leptos::Callback;
+use web_sys::{AbortSignal, File};
+
+/// File as known once uploaded, e.g. with the address it is served from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FileDescriptor {
+    pub name: String,
+    /// size in bytes
+    pub size: u64,
+    /// media type, empty when the browser could not guess it
+    pub mime_type: String,
+    pub url: Option<String>,
+}
+
+impl FileDescriptor {
+    pub fn new(name: String, size: u64, mime_type: String) -> Self {
+        Self {
+            name,
+            size,
+            mime_type,
+            url: None,
+        }
+    }
+
+    /// Descriptor of a picked file, before it is uploaded.
+    pub fn from_file(file: &File) -> Self {
+        Self::new(file.name(), file.size() as u64, file.type_())
+    }
+
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.mime_type.starts_with("image/")
+    }
+}
+
+/// Whether a file matches the `accept` attribute of file inputs, a comma separated
+/// list of extensions (`.pdf`), media types (`application/pdf`) or wildcards (`image/*`).
+///
+/// An empty list accepts every file.
+pub fn accepts(accept: &str, name: &str, mime_type: &str) -> bool {
+    let mut patterns = accept
+        .split(',')
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .peekable();
+
+    if patterns.peek().is_none() {
+        return true;
+    }
+
+    let name = name.to_lowercase();
+    let mime_type = mime_type.to_lowercase();
+
+    patterns.any(|pattern| {
+        let pattern = pattern.to_lowercase();
+
+        if pattern.starts_with('.') {
+            name.ends_with(&pattern)
+        } else if let Some(family) = pattern.strip_suffix("/*") {
+            mime_type.split('/').next() == Some(family)
+        } else {
+            mime_type == pattern
+        }
+    })
+}
+
+/// Size in bytes for people, in decimal units, e.g. `512 B`, `1.5 KB` or `12 MB`.
+pub fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if size < 1000 {
+        return format!("{size} B");
+    }
+
+    let mut value = size as f64;
+    let mut unit = UNITS[0];
+
+    for next in UNITS {
+        value /= 1000.0;
+        unit = next;
+
+        if value < 1000.0 {
+            break;
+        }
+    }
+
+    // a decimal only while it tells something
+    match value < 10.0 && (value * 10.0).round() % 10.0 != 0.0 {
+        true => format!("{value:.1} {unit}"),
+        false => format!("{value:.0} {unit}"),
+    }
+}
+
+/// A file handed over to the upload callback.
+pub struct UploadRequest {
+    pub file: File,
+    /// reports the progress of the upload, from `0.0` to `1.0`
+    pub progress: Callback<f64>,
+    /// aborted when the user cancels the upload, e.g. to pass on to `fetch`
+    pub abort_signal: AbortSignal,
+}
+
+pub type UploadResult = Result<FileDescriptor, String>;
+
+pub type UploadFuture = Pin<Box<dyn Future<Output = UploadResult>>>;
+
+/// Uploads the files picked in a `FileUpload`.
+///
+/// The future resolves to the descriptor of the uploaded file, or to the
+/// message shown to the user when the upload failed.
+#[derive(Clone)]
+pub struct Uploader(Rc<dyn Fn(UploadRequest) -> UploadFuture>);
+
+impl Uploader {
+    pub fn new<F, Fut>(upload: F) -> Self
+    where
+        F: Fn(UploadRequest) -> Fut + 'static,
+        Fut: Future<Output = UploadResult> + 'static,
+    {
+        Self(Rc::new(move |request| Box::pin(upload(request))))
+    }
+
+    pub fn upload(&self, request: UploadRequest) -> UploadFuture {
+        (self.0)(request)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::file::{accepts, format_size};
+
+    #[test]
+    fn it_matches_the_accepted_types() {
+        assert!(accepts("", "notes.txt", "text/plain"));
+        assert!(accepts("image/*, .pdf", "photo.JPG", "image/jpeg"));
+        assert!(accepts("image/*, .pdf", "report.PDF", ""));
+        assert!(accepts("application/json", "data", "application/json"));
+        assert!(!accepts("image/*, .pdf", "notes.txt", "text/plain"));
+        assert!(!accepts("image/*", "archive.zip", "application/zip"));
+    }
+
+    #[test]
+    fn it_formats_the_sizes() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1000), "1 KB");
+        assert_eq!(format_size(1500), "1.5 KB");
+        assert_eq!(format_size(12_400_000), "12 MB");
+        assert_eq!(format_size(3_000_000_000), "3 GB");
+    }
+}
@@ -0,0 +1,522 @@
+use std::{rc::Rc, sync::Arc};
+
+use futures::future::{abortable, AbortHandle};
+use leptos::{
+    component, create_effect, create_node_ref, create_rw_signal,
+    ev::{DragEvent, Event, FocusEvent, MouseEvent},
+    html::Input,
+    on_cleanup, spawn_local, store_value, view, with_owner, Callable, Callback, For, IntoView,
+    Owner, RwSignal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
+    SignalWithUntracked, StoredValue,
+};
+use web_sys::{AbortController, File, FileList, Url};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    file::{format_size, FileDescriptor, UploadRequest, Uploader},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        feedback::default_err_message,
+        validator::{ControlValidator, ValidatorKey, ValidatorMetadata},
+        validators::{accept_type::AcceptType, max_file_size::MaxFileSize},
+    },
+    icons::{arrow_up_tray::ArrowUpTray, document::Document, x_mark::XMark},
+    id::resolve_id,
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-file-upload flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5";
+
+const DROP_ZONE_STYLE_CLASS: &str =
+    "lumx-file-upload-zone flex flex-col items-center gap-y-1 rounded-md border-2 border-dashed p-4 text-sm text-center cursor-pointer focus-within:border-blue-600";
+
+#[derive(Debug, Clone, PartialEq)]
+enum UploadStatus {
+    /// progress from `0.0` to `1.0`
+    Uploading(f64),
+    Uploaded,
+    Failed(String),
+    /// the file does not match `accept` or `max_size` and is not uploaded
+    Rejected(String),
+}
+
+#[derive(Clone)]
+struct UploadEntry {
+    key: usize,
+    descriptor: FileDescriptor,
+    status: RwSignal<UploadStatus>,
+    preview: Option<String>,
+    /// the preview is an object url of the picked file, to be revoked
+    owns_preview: bool,
+    abort: Option<(AbortHandle, AbortController)>,
+}
+
+impl UploadEntry {
+    /// Entry of a file already in the control value.
+    fn uploaded(key: usize, descriptor: FileDescriptor) -> Self {
+        let preview = descriptor
+            .is_image()
+            .then(|| descriptor.url.clone())
+            .flatten();
+
+        Self {
+            key,
+            descriptor,
+            status: create_rw_signal(UploadStatus::Uploaded),
+            preview,
+            owns_preview: false,
+            abort: None,
+        }
+    }
+
+    /// Stops a running upload and frees the preview.
+    fn release(&self) {
+        if let Some((handle, controller)) = &self.abort {
+            handle.abort();
+            controller.abort();
+        }
+
+        if let Some(preview) = self.preview.as_ref().filter(|_| self.owns_preview) {
+            let _ = Url::revoke_object_url(preview);
+        }
+    }
+}
+
+fn file_list(files: FileList) -> Vec<File> {
+    (0..files.length())
+        .filter_map(|index| files.get(index))
+        .collect()
+}
+
+/// Picks files from a drop zone or the file dialog and uploads them one by one.
+///
+/// The control holds the descriptors returned by `uploader` for the files uploaded
+/// successfully. Files that do not match `accept` or `max_size` are listed with the
+/// message of the `AcceptType` or `MaxFileSize` validator, without being uploaded.
+#[component]
+pub fn FileUpload(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "Drop files here or click to browse")] placeholder: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    /// several files can be picked, otherwise a new file replaces the current one
+    #[prop(optional)]
+    multiple: bool,
+    /// file types that can be picked, as the `accept` attribute of file inputs, e.g. `image/*,.pdf`
+    #[prop(optional)]
+    accept: Option<&'static str>,
+    /// largest size of a file in bytes
+    #[prop(optional)]
+    max_size: Option<u64>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    uploader: Uploader,
+    control: Arc<FormControl<Vec<FileDescriptor>>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "file-upload");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let input_ref = create_node_ref::<Input>();
+    let control_value = control.value;
+    let entries: RwSignal<Vec<UploadEntry>> = create_rw_signal(Vec::new());
+    let next_key: StoredValue<usize> = store_value(0);
+    let dragging = create_rw_signal(false);
+
+    // the status signals of the entries outlive the handlers and effects creating them
+    let owner = Owner::current().expect("FileUpload is rendered in a reactive owner");
+
+    let take_key = move || {
+        let key = next_key.get_value();
+        next_key.set_value(key + 1);
+        key
+    };
+
+    let mut checks: Vec<Box<dyn ControlValidator<Vec<FileDescriptor>>>> = Vec::new();
+    checks.extend(accept.map(|accept| Box::new(AcceptType::new(accept)) as Box<_>));
+    checks.extend(max_size.map(|max_size| Box::new(MaxFileSize::new(max_size)) as Box<_>));
+    let checks = Rc::new(checks);
+
+    let rejection = move |descriptor: &FileDescriptor| {
+        checks
+            .iter()
+            .find_map(|check| check.validate(Some(vec![descriptor.clone()])).err())
+            .map(|(key, meta)| match err_message {
+                Some(err_message) => err_message.call((key, meta)),
+                None => default_err_message(&key, &meta),
+            })
+    };
+
+    // keeps the uploaded entries in line with the control value, e.g. once reset
+    create_effect(move |_| {
+        let mut missing = control_value.get().unwrap_or_default();
+
+        entries.update(|entries| {
+            entries.retain(|entry| {
+                if entry.status.get_untracked() != UploadStatus::Uploaded {
+                    return true;
+                }
+
+                match missing.iter().position(|file| *file == entry.descriptor) {
+                    Some(index) => {
+                        missing.remove(index);
+                        true
+                    }
+                    None => {
+                        entry.release();
+                        false
+                    }
+                }
+            });
+
+            with_owner(owner, || {
+                entries.extend(
+                    missing
+                        .into_iter()
+                        .map(|descriptor| UploadEntry::uploaded(take_key(), descriptor)),
+                );
+            });
+        });
+    });
+
+    on_cleanup(move || {
+        entries.with_untracked(|entries| entries.iter().for_each(UploadEntry::release));
+    });
+
+    let set_files = {
+        let control_ref = Arc::clone(&control);
+
+        move |files: Vec<FileDescriptor>| {
+            control_ref.mark_as_dirty();
+            control_ref.set_value((!files.is_empty()).then_some(files));
+        }
+    };
+
+    let upload = {
+        let set_files = set_files.clone();
+
+        move |file: File, key: usize, status: RwSignal<UploadStatus>| {
+            let Ok(controller) = AbortController::new() else {
+                status.set(UploadStatus::Failed(String::from(
+                    "The upload could not be started.",
+                )));
+                return None;
+            };
+
+            let progress = Callback::new(move |progress: f64| {
+                if status.get_untracked() != UploadStatus::Uploaded {
+                    status.set(UploadStatus::Uploading(progress.clamp(0.0, 1.0)));
+                }
+            });
+
+            let (task, handle) = abortable(uploader.upload(UploadRequest {
+                file,
+                progress,
+                abort_signal: controller.signal(),
+            }));
+
+            let set_files = set_files.clone();
+
+            spawn_local(async move {
+                // a cancelled upload leaves no trace
+                let Ok(result) = task.await else {
+                    return;
+                };
+
+                match result {
+                    Ok(descriptor) => {
+                        entries.update(|entries| {
+                            if let Some(entry) = entries.iter_mut().find(|entry| entry.key == key) {
+                                entry.descriptor = descriptor.clone();
+                                entry.abort = None;
+                            }
+                        });
+                        status.set(UploadStatus::Uploaded);
+
+                        let mut files = control_value.get_untracked().unwrap_or_default();
+                        files.push(descriptor);
+                        set_files(files);
+                    }
+                    Err(message) => status.set(UploadStatus::Failed(message)),
+                }
+            });
+
+            Some((handle, controller))
+        }
+    };
+
+    let add_files = {
+        let control_ref = Arc::clone(&control);
+
+        move |files: Vec<File>| {
+            let files: Vec<File> = match multiple {
+                true => files,
+                false => files.into_iter().take(1).collect(),
+            };
+
+            if files.is_empty() {
+                return;
+            }
+
+            if !multiple {
+                entries.update(|entries| {
+                    entries.drain(..).for_each(|entry| entry.release());
+                });
+                control_ref.set_value(None);
+            }
+
+            control_ref.mark_as_dirty();
+            control_ref.mark_as_touched();
+
+            for file in files {
+                let key = take_key();
+                let descriptor = FileDescriptor::from_file(&file);
+                let rejected = rejection(&descriptor);
+
+                let status = with_owner(owner, || {
+                    create_rw_signal(match &rejected {
+                        Some(message) => UploadStatus::Rejected(message.clone()),
+                        None => UploadStatus::Uploading(0.0),
+                    })
+                });
+
+                let preview = (rejected.is_none() && descriptor.is_image())
+                    .then(|| Url::create_object_url_with_blob(&file).ok())
+                    .flatten();
+
+                let abort = match rejected {
+                    Some(_) => None,
+                    None => with_owner(owner, || upload(file, key, status)),
+                };
+
+                entries.update(|entries| {
+                    entries.push(UploadEntry {
+                        key,
+                        descriptor,
+                        status,
+                        owns_preview: preview.is_some(),
+                        preview,
+                        abort,
+                    })
+                });
+            }
+        }
+    };
+
+    let on_change = {
+        let add_files = add_files.clone();
+
+        move |_ev: Event| {
+            let Some(input) = input_ref.get_untracked() else {
+                return;
+            };
+
+            add_files(input.files().map(file_list).unwrap_or_default());
+
+            // the same file can be picked again
+            input.set_value("");
+        }
+    };
+
+    let on_drop = move |ev: DragEvent| {
+        ev.prevent_default();
+        dragging.set(false);
+
+        let files = ev.data_transfer().and_then(|transfer| transfer.files());
+        add_files(files.map(file_list).unwrap_or_default());
+    };
+
+    let on_drag_over = move |ev: DragEvent| {
+        ev.prevent_default();
+        dragging.set(true);
+    };
+
+    let on_remove = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |key: usize| {
+            let Some(entry) = entries
+                .with_untracked(|entries| entries.iter().find(|entry| entry.key == key).cloned())
+            else {
+                return;
+            };
+
+            entry.release();
+            entries.update(|entries| entries.retain(|entry| entry.key != key));
+
+            if entry.status.get_untracked() == UploadStatus::Uploaded {
+                let mut files = control_value.get_untracked().unwrap_or_default();
+
+                if let Some(index) = files.iter().position(|file| *file == entry.descriptor) {
+                    files.remove(index);
+                }
+
+                set_files(files);
+            }
+
+            control_ref.mark_as_touched();
+        }
+    });
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| control_ref.mark_as_touched()
+    };
+
+    // file upload specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600"),
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200"),
+    };
+
+    let drop_zone_class = move || match (dragging.get(), visible_err.with(Option::is_some)) {
+        (true, _) => format!("{DROP_ZONE_STYLE_CLASS} border-blue-600 bg-blue-50 text-blue-700"),
+        (false, true) => format!("{DROP_ZONE_STYLE_CLASS} border-red-600 text-slate-500"),
+        (false, false) => format!("{DROP_ZONE_STYLE_CLASS} border-slate-200 text-slate-500"),
+    };
+
+    // the feedback region only describes the control while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let constraints = {
+        let accepted = accept.map(|accept| accept.replace(',', ", "));
+        let largest = max_size.map(|max_size| format!("up to {}", format_size(max_size)));
+
+        [accepted, largest]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    view! {
+        <div class=class_props_by_state>
+            <div id=label_id.clone() class="lumx-file-upload-label text-xs text-slate-900 mb-1.5">
+                {label}
+            </div>
+
+            <label class=drop_zone_class
+                on:dragover=on_drag_over
+                on:dragleave=move |_ev: DragEvent| dragging.set(false)
+                on:drop=on_drop>
+                <ArrowUpTray class="w-6 h-6" />
+                <span>{placeholder}</span>
+                {(!constraints.is_empty()).then(|| view! {
+                    <span class="lumx-file-upload-constraints text-xs text-slate-400">{constraints}</span>
+                })}
+                <input node_ref=input_ref
+                    id=form_id
+                    class="sr-only"
+                    type="file"
+                    multiple=multiple
+                    accept=accept
+                    aria-labelledby=label_id
+                    aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                    aria-describedby=described_by
+                    on:change=on_change
+                    on:blur=on_blur />
+            </label>
+
+            <ul class="lumx-file-upload-files flex flex-col divide-y divide-slate-100">
+                <For each=move || entries.get()
+                    key=|entry| entry.key
+                    let:entry>
+                    <FileUploadItem entry=entry on_remove=on_remove />
+                </For>
+            </ul>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </div>
+    }
+}
+
+#[component]
+fn FileUploadItem(entry: UploadEntry, on_remove: Callback<usize>) -> impl IntoView {
+    let UploadEntry {
+        key,
+        descriptor,
+        status,
+        preview,
+        ..
+    } = entry;
+
+    let name = descriptor.name;
+    let uploading = move || matches!(status.get(), UploadStatus::Uploading(_));
+
+    let remove_label = {
+        let name = name.clone();
+
+        move || match uploading() {
+            true => format!("cancel the upload of {name}"),
+            false => format!("remove {name}"),
+        }
+    };
+
+    let progress_label = format!("uploading {name}");
+
+    let state = move || match status.get() {
+        UploadStatus::Uploading(progress) => {
+            let percent = (progress * 100.0).round();
+
+            Some(view! {
+                <div class="lumx-file-upload-progress h-1 mt-1 rounded-full bg-slate-200 overflow-hidden"
+                    role="progressbar"
+                    aria-label=progress_label.clone()
+                    aria-valuemin="0"
+                    aria-valuemax="100"
+                    aria-valuenow=percent>
+                    <div class="h-full bg-blue-600" style=format!("width: {percent}%")></div>
+                </div>
+            }
+            .into_view())
+        }
+        UploadStatus::Uploaded => None,
+        UploadStatus::Failed(message) | UploadStatus::Rejected(message) => Some(
+            view! {
+                <span class="lumx-file-upload-error text-xs text-red-600">{message}</span>
+            }
+            .into_view(),
+        ),
+    };
+
+    view! {
+        <li class="lumx-file-upload-item flex flex-row items-center gap-x-2 py-1.5"
+            class=("lumx-invalid", move || {
+                matches!(status.get(), UploadStatus::Failed(_) | UploadStatus::Rejected(_))
+            })>
+            {match preview {
+                Some(preview) => view! {
+                    <img class="lumx-file-upload-preview w-10 h-10 shrink-0 rounded object-cover" src=preview alt="" />
+                }
+                .into_view(),
+                None => view! {
+                    <div class="w-10 h-10 shrink-0 flex items-center justify-center rounded bg-slate-100 text-slate-500">
+                        <Document class="w-5 h-5" />
+                    </div>
+                }
+                .into_view(),
+            }}
+
+            <div class="flex flex-col grow min-w-0">
+                <div class="flex flex-row justify-between gap-x-2 text-sm text-slate-900">
+                    <span class="truncate">{name}</span>
+                    <span class="shrink-0 text-xs text-slate-500">{format_size(descriptor.size)}</span>
+                </div>
+                {state}
+            </div>
+
+            <button type="button"
+                aria-label=remove_label
+                on:click=move |_ev: MouseEvent| on_remove.call(key)
+                class="lumx-file-upload-remove shrink-0 cursor-pointer rounded-full p-0.5 text-slate-900 hover:bg-slate-200">
+                <XMark class="w-4 h-4" />
+            </button>
+        </li>
+    }
+}
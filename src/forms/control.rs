@@ -1,8 +1,8 @@
-use std::{cell::RefCell, collections::HashMap, ops::Not};
+use std::ops::Not;
 
-use leptos::{create_rw_signal, RwSignal, SignalGetUntracked, SignalSet};
+use leptos::{create_rw_signal, RwSignal, SignalGet, SignalGetUntracked, SignalSet, SignalWith};
 
-use super::validator::{ControlValidator, ValidatorMetadata};
+use super::validator::{ControlValidator, ValidatorKey, ValidatorMetadata};
 
 pub trait AbstractFormControl<T>
 where
@@ -12,8 +12,18 @@ where
     fn set_value(&self, new_value: Option<T>);
     fn has_err(&self, name: &str) -> bool;
     fn err(&self, name: &str) -> Option<ValidatorMetadata>;
+    /// settings of the validator of that name, whether it fails or not
+    fn constraint(&self, name: &str) -> Option<ValidatorMetadata>;
+    /// validation errors in the order of the validators
+    fn errs(&self) -> Vec<(ValidatorKey, ValidatorMetadata)>;
     fn has_errs(&self) -> bool;
     fn is_valid(&self) -> bool;
+    /// the user has left the control at least once
+    fn is_touched(&self) -> bool;
+    fn mark_as_touched(&self);
+    /// the user has changed the value at least once
+    fn is_dirty(&self) -> bool;
+    fn mark_as_dirty(&self);
 }
 
 pub struct FormControl<T>
@@ -22,8 +32,10 @@ where
 {
     pub value: RwSignal<Option<T>>,
     /// keep validations errors with their metadata
-    errs: RefCell<HashMap<String, ValidatorMetadata>>,
+    errs: RwSignal<Vec<(ValidatorKey, ValidatorMetadata)>>,
     validators: Vec<Box<dyn ControlValidator<T>>>,
+    touched: RwSignal<bool>,
+    dirty: RwSignal<bool>,
 }
 
 impl<T> FormControl<T>
@@ -33,8 +45,10 @@ where
     pub fn new(default_value: Option<T>, validators: Vec<Box<dyn ControlValidator<T>>>) -> Self {
         let form_ctrl = Self {
             value: create_rw_signal(default_value),
-            errs: RefCell::new(HashMap::new()),
+            errs: create_rw_signal(Vec::new()),
             validators,
+            touched: create_rw_signal(false),
+            dirty: create_rw_signal(false),
         };
         form_ctrl.update_and_validity();
 
@@ -47,16 +61,18 @@ where
     T: Clone + 'static,
 {
     fn update_and_validity(&self) {
-        // clear the preceding errors
-        self.errs.borrow_mut().clear();
+        let mut errs: Vec<(ValidatorKey, ValidatorMetadata)> = Vec::new();
 
         for validator in self.validators.iter() {
             if let Some(form_value) = self.value.try_get_untracked() {
-                if let Err(errs) = validator.validate(form_value) {
-                    self.errs.borrow_mut().insert(errs.0, errs.1);
+                if let Err(err) = validator.validate(form_value) {
+                    errs.push(err);
                 }
             }
         }
+
+        // replace the preceding errors
+        self.errs.set(errs);
     }
 
     fn set_value(&self, new_value: Option<T>) {
@@ -65,26 +81,57 @@ where
     }
 
     fn has_err(&self, name: &str) -> bool {
-        self.errs.borrow().contains_key(name)
+        self.errs
+            .with(|errs| errs.iter().any(|(key, _)| key == name))
     }
 
     fn err(&self, name: &str) -> Option<ValidatorMetadata> {
-        self.errs
-            .borrow()
-            .get(name)
-            .and_then(|val| Some(val.clone()))
+        self.errs.with(|errs| {
+            errs.iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, meta)| meta.clone())
+        })
+    }
+
+    fn constraint(&self, name: &str) -> Option<ValidatorMetadata> {
+        self.validators
+            .iter()
+            .filter_map(|validator| validator.constraint())
+            .find(|(key, _)| key == name)
+            .map(|(_, meta)| meta)
+    }
+
+    fn errs(&self) -> Vec<(ValidatorKey, ValidatorMetadata)> {
+        self.errs.get()
     }
 
     fn has_errs(&self) -> bool {
-        self.errs.borrow().is_empty().not()
+        self.errs.with(|errs| errs.is_empty().not())
     }
 
     fn is_valid(&self) -> bool {
-        self.errs.borrow().is_empty()
+        self.errs.with(|errs| errs.is_empty())
+    }
+
+    fn is_touched(&self) -> bool {
+        self.touched.get()
+    }
+
+    fn mark_as_touched(&self) {
+        self.touched.set(true);
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    fn mark_as_dirty(&self) {
+        self.dirty.set(true);
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod test {
 
     mod text_control {
@@ -137,6 +184,104 @@ mod test {
                 runtime.dispose();
             }
         }
+
+        mod text_control_max_length {
+
+            use leptos::create_runtime;
+
+            use crate::forms::{
+                control::{AbstractFormControl, FormControl},
+                validators::{
+                    max_length::{MaxLength, MAX_LENGTH_NAME},
+                    min_length::{MinLength, MIN_LENGTH_NAME},
+                },
+            };
+
+            #[test]
+            fn it_validates_the_max_length_in_chars() {
+                let runtime = create_runtime();
+
+                let form_control: FormControl<String> = FormControl::new(
+                    Some(String::from("añejo")),
+                    vec![Box::new(MaxLength::new(5))],
+                );
+
+                assert!(form_control.is_valid());
+
+                form_control.set_value(Some(String::from("añejos")));
+                assert!(form_control.has_err(MAX_LENGTH_NAME));
+                assert_eq!(
+                    form_control
+                        .err(MAX_LENGTH_NAME)
+                        .and_then(|meta| meta.get("actual_length")),
+                    Some(6)
+                );
+                assert_eq!(
+                    form_control
+                        .constraint(MAX_LENGTH_NAME)
+                        .and_then(|meta| meta.get("max_length")),
+                    Some(5)
+                );
+
+                runtime.dispose();
+            }
+
+            #[test]
+            fn it_validates_the_min_length_in_chars() {
+                let runtime = create_runtime();
+
+                let form_control: FormControl<String> = FormControl::new(
+                    Some(String::from(" ñu ")),
+                    vec![Box::new(MinLength::new(3))],
+                );
+
+                assert!(form_control.has_err(MIN_LENGTH_NAME));
+                assert_eq!(
+                    form_control
+                        .err(MIN_LENGTH_NAME)
+                        .and_then(|meta| meta.get("actual_length")),
+                    Some(2)
+                );
+
+                form_control.set_value(Some(String::from("ñus")));
+                assert!(form_control.is_valid());
+
+                runtime.dispose();
+            }
+        }
+
+        mod text_control_email {
+
+            use leptos::create_runtime;
+
+            use crate::{
+                forms::{
+                    control::{AbstractFormControl, FormControl},
+                    validators::email::EMAIL_NAME,
+                },
+                input_text::InputType,
+            };
+
+            #[test]
+            fn it_validates_an_email_control_from_the_input_type() {
+                let runtime = create_runtime();
+
+                let form_control: FormControl<String> = FormControl::new(
+                    Some(String::from("john.doe@")),
+                    InputType::Email.validators(),
+                );
+
+                assert!(form_control.has_err(EMAIL_NAME));
+
+                form_control.set_value(Some(String::from("john.doe@example.com")));
+                assert!(form_control.is_valid());
+
+                form_control.set_value(None);
+                assert!(form_control.is_valid());
+
+                runtime.dispose();
+            }
+        }
     }
 
     mod option_control {
@@ -146,7 +291,7 @@ mod test {
         use crate::forms::{
             control::{AbstractFormControl, FormControl},
             validators::{
-                min::Min,
+                min::{Min, MIN_NAME},
                 required::{Required, REQUIRED_NAME},
             },
         };
@@ -176,5 +321,297 @@ mod test {
 
             runtime.dispose();
         }
+
+        #[test]
+        fn it_validates_a_decimal_min() {
+            let runtime = create_runtime();
+
+            let form_control: FormControl<f64> =
+                FormControl::new(Some(1.2), vec![Box::new(Min::new(1.25))]);
+
+            assert!(form_control.has_err(MIN_NAME));
+            assert_eq!(
+                form_control
+                    .err(MIN_NAME)
+                    .and_then(|meta| meta.get_number("actual_value")),
+                Some(1.2)
+            );
+            assert_eq!(
+                form_control
+                    .constraint(MIN_NAME)
+                    .and_then(|meta| meta.get_number("min_val")),
+                Some(1.25)
+            );
+
+            form_control.set_value(Some(1.25));
+            assert!(form_control.is_valid());
+
+            runtime.dispose();
+        }
+    }
+
+    mod selection_control {
+
+        use leptos::create_runtime;
+
+        use crate::forms::{
+            control::{AbstractFormControl, FormControl},
+            validators::{
+                max_selected::{MaxSelected, MAX_SELECTED_NAME},
+                min_selected::{MinSelected, MIN_SELECTED_NAME},
+            },
+        };
+
+        #[test]
+        fn it_validates_the_selected_count() {
+            let runtime = create_runtime();
+
+            let form_control: FormControl<Vec<i32>> = FormControl::new(
+                None,
+                vec![Box::new(MinSelected::new(1)), Box::new(MaxSelected::new(2))],
+            );
+
+            assert!(form_control.has_err(MIN_SELECTED_NAME));
+
+            form_control.set_value(Some(vec![1, 2]));
+            assert!(form_control.is_valid());
+
+            form_control.set_value(Some(vec![1, 2, 3]));
+            assert!(form_control.has_err(MAX_SELECTED_NAME));
+            assert_eq!(
+                form_control
+                    .err(MAX_SELECTED_NAME)
+                    .and_then(|meta| meta.get("actual_selected")),
+                Some(3)
+            );
+
+            runtime.dispose();
+        }
+    }
+
+    mod list_control {
+
+        use leptos::create_runtime;
+
+        use crate::forms::{
+            control::{AbstractFormControl, FormControl},
+            validators::{
+                each_item::EachItem,
+                email::{Email, EMAIL_NAME},
+            },
+        };
+
+        #[test]
+        fn it_validates_every_item() {
+            let runtime = create_runtime();
+
+            let form_control: FormControl<Vec<String>> = FormControl::new(
+                Some(vec![String::from("john.doe@example.com")]),
+                vec![Box::new(EachItem::new(vec![Box::new(Email::new())]))],
+            );
+
+            assert!(form_control.is_valid());
+
+            form_control.set_value(Some(vec![
+                String::from("john.doe@example.com"),
+                String::from("jane.doe@"),
+            ]));
+            assert!(form_control.has_err(EMAIL_NAME));
+            assert_eq!(
+                form_control
+                    .err(EMAIL_NAME)
+                    .and_then(|meta| meta.get("item_index")),
+                Some(1)
+            );
+
+            runtime.dispose();
+        }
+    }
+
+    mod bool_control {
+
+        use leptos::create_runtime;
+
+        use crate::forms::{
+            control::{AbstractFormControl, FormControl},
+            validators::required_true::{RequiredTrue, REQUIRED_TRUE_NAME},
+        };
+
+        #[test]
+        fn it_requires_a_checked_value() {
+            let runtime = create_runtime();
+
+            let form_control: FormControl<bool> =
+                FormControl::new(None, vec![Box::new(RequiredTrue::new())]);
+
+            assert!(form_control.has_err(REQUIRED_TRUE_NAME));
+
+            form_control.set_value(Some(false));
+            assert!(form_control.has_err(REQUIRED_TRUE_NAME));
+
+            form_control.set_value(Some(true));
+            assert!(form_control.is_valid());
+
+            runtime.dispose();
+        }
+    }
+
+    mod date_control {
+
+        use chrono::NaiveDate;
+        use leptos::create_runtime;
+
+        use crate::forms::{
+            control::{AbstractFormControl, FormControl},
+            validators::{
+                max_date::{MaxDate, MAX_DATE_NAME},
+                min_date::{MinDate, MIN_DATE_NAME},
+            },
+        };
+
+        #[test]
+        fn it_validates_the_date_bounds() {
+            let runtime = create_runtime();
+            let date = |day| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+
+            let form_control: FormControl<NaiveDate> = FormControl::new(
+                Some(date(15)),
+                vec![
+                    Box::new(MinDate::new(date(10))),
+                    Box::new(MaxDate::new(date(20))),
+                ],
+            );
+
+            assert!(form_control.is_valid());
+
+            form_control.set_value(Some(date(9)));
+            assert!(form_control.has_err(MIN_DATE_NAME));
+            assert_eq!(
+                form_control
+                    .err(MIN_DATE_NAME)
+                    .and_then(|meta| meta.get("min_date"))
+                    .and_then(NaiveDate::from_num_days_from_ce_opt),
+                Some(date(10))
+            );
+
+            form_control.set_value(Some(date(21)));
+            assert!(form_control.has_err(MAX_DATE_NAME));
+
+            runtime.dispose();
+        }
+    }
+
+    mod time_control {
+
+        use chrono::NaiveTime;
+        use leptos::create_runtime;
+
+        use crate::forms::{
+            control::{AbstractFormControl, FormControl},
+            validators::{
+                max_time::{MaxTime, MAX_TIME_NAME},
+                min_time::{MinTime, MIN_TIME_NAME},
+            },
+        };
+
+        #[test]
+        fn it_validates_the_time_bounds() {
+            let runtime = create_runtime();
+            let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+
+            let form_control: FormControl<NaiveTime> = FormControl::new(
+                Some(time(12)),
+                vec![
+                    Box::new(MinTime::new(time(9))),
+                    Box::new(MaxTime::new(time(17))),
+                ],
+            );
+
+            assert!(form_control.is_valid());
+
+            form_control.set_value(Some(time(8)));
+            assert!(form_control.has_err(MIN_TIME_NAME));
+            assert_eq!(
+                form_control
+                    .err(MIN_TIME_NAME)
+                    .and_then(|meta| meta.get("min_time")),
+                Some(9 * 60 * 60)
+            );
+
+            form_control.set_value(Some(time(18)));
+            assert!(form_control.has_err(MAX_TIME_NAME));
+
+            runtime.dispose();
+        }
+    }
+
+    mod file_control {
+
+        use leptos::create_runtime;
+
+        use crate::{
+            file::FileDescriptor,
+            forms::{
+                control::{AbstractFormControl, FormControl},
+                feedback::default_err_message,
+                validators::{
+                    accept_type::{AcceptType, ACCEPT_TYPE_NAME},
+                    max_file_size::{MaxFileSize, MAX_FILE_SIZE_NAME},
+                },
+            },
+        };
+
+        #[test]
+        fn it_validates_the_file_types_and_sizes() {
+            let runtime = create_runtime();
+            let file = |name: &str, size, mime_type: &str| {
+                FileDescriptor::new(String::from(name), size, String::from(mime_type))
+            };
+
+            let form_control: FormControl<Vec<FileDescriptor>> = FormControl::new(
+                Some(vec![file("photo.png", 2_000, "image/png")]),
+                vec![
+                    Box::new(AcceptType::new("image/*,.pdf")),
+                    Box::new(MaxFileSize::new(1_000_000)),
+                ],
+            );
+
+            assert!(form_control.is_valid());
+
+            form_control.set_value(Some(vec![
+                file("photo.png", 2_000, "image/png"),
+                file("notes.txt", 100, "text/plain"),
+            ]));
+            assert!(form_control.has_err(ACCEPT_TYPE_NAME));
+            assert_eq!(
+                form_control
+                    .err(ACCEPT_TYPE_NAME)
+                    .and_then(|meta| meta.get("file_index")),
+                Some(1)
+            );
+
+            form_control.set_value(Some(vec![file("scan.pdf", 5_000_000, "")]));
+            assert!(form_control.has_err(MAX_FILE_SIZE_NAME));
+            assert_eq!(
+                form_control
+                    .err(MAX_FILE_SIZE_NAME)
+                    .and_then(|meta| meta.get("actual_size_kb")),
+                Some(5_000)
+            );
+
+            let form_control: FormControl<Vec<FileDescriptor>> = FormControl::new(
+                Some(vec![file("backup.zip", 6_000_000_000, "")]),
+                vec![Box::new(MaxFileSize::new(5_000_000_000))],
+            );
+            let err = form_control.err(MAX_FILE_SIZE_NAME).unwrap_or_default();
+
+            assert_eq!(err.get("max_size_kb"), Some(5_000_000));
+            assert_eq!(
+                default_err_message(&String::from(MAX_FILE_SIZE_NAME), &err),
+                "Files must be at most 5 GB."
+            );
+
+            runtime.dispose();
+        }
     }
 }
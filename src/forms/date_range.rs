@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use leptos::{create_effect, SignalGet};
+
+use super::{
+    control::{AbstractFormControl, FormControl},
+    validator::ControlValidator,
+    validators::date_range_order::DateRangeOrder,
+};
+
+/// Start and end date controls of a period.
+///
+/// The end control gets a `DateRangeOrder` validator on top of its own ones, so an end before
+/// the start is reported like any other error of the end control.
+#[derive(Clone)]
+pub struct DateRangeControl {
+    pub start: Arc<FormControl<NaiveDate>>,
+    pub end: Arc<FormControl<NaiveDate>>,
+}
+
+impl DateRangeControl {
+    #[allow(clippy::arc_with_non_send_sync)]
+    pub fn new(
+        range: Option<(NaiveDate, NaiveDate)>,
+        start_validators: Vec<Box<dyn ControlValidator<NaiveDate>>>,
+        mut end_validators: Vec<Box<dyn ControlValidator<NaiveDate>>>,
+    ) -> Self {
+        let start = Arc::new(FormControl::new(
+            range.map(|(start, _)| start),
+            start_validators,
+        ));
+
+        end_validators.push(Box::new(DateRangeOrder::new(start.value)));
+        let end = Arc::new(FormControl::new(range.map(|(_, end)| end), end_validators));
+
+        // the end is checked again when the start is written to directly, effects do not run
+        // on the server so the setters below check it themselves
+        create_effect({
+            let end = Arc::clone(&end);
+            let start_value = start.value;
+
+            move |_| {
+                start_value.get();
+                end.update_and_validity();
+            }
+        });
+
+        Self { start, end }
+    }
+
+    /// both dates, once set
+    pub fn range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.start.value.get().zip(self.end.value.get())
+    }
+
+    pub fn set_start(&self, start: Option<NaiveDate>) {
+        self.start.set_value(start);
+        self.end.update_and_validity();
+    }
+
+    pub fn set_end(&self, end: Option<NaiveDate>) {
+        self.end.set_value(end);
+    }
+
+    pub fn set_range(&self, range: Option<(NaiveDate, NaiveDate)>) {
+        self.set_start(range.map(|(start, _)| start));
+        self.set_end(range.map(|(_, end)| end));
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.start.is_valid() && self.end.is_valid()
+    }
+
+    pub fn mark_as_touched(&self) {
+        self.start.mark_as_touched();
+        self.end.mark_as_touched();
+    }
+
+    pub fn mark_as_dirty(&self) {
+        self.start.mark_as_dirty();
+        self.end.mark_as_dirty();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use leptos::create_runtime;
+
+    use crate::forms::{
+        control::AbstractFormControl,
+        date_range::DateRangeControl,
+        validators::{
+            date_range_order::DATE_RANGE_ORDER_NAME,
+            required::{Required, REQUIRED_NAME},
+        },
+    };
+
+    #[test]
+    fn it_reports_an_end_before_the_start() {
+        let runtime = create_runtime();
+        let date = |day| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+
+        let control = DateRangeControl::new(
+            Some((date(1), date(7))),
+            vec![Box::new(Required::new())],
+            vec![],
+        );
+
+        assert!(control.is_valid());
+        assert_eq!(control.range(), Some((date(1), date(7))));
+
+        control.set_start(Some(date(9)));
+        assert!(control.end.has_err(DATE_RANGE_ORDER_NAME));
+        assert_eq!(
+            control
+                .end
+                .err(DATE_RANGE_ORDER_NAME)
+                .and_then(|meta| meta.get("start_date"))
+                .and_then(NaiveDate::from_num_days_from_ce_opt),
+            Some(date(9))
+        );
+
+        control.set_end(Some(date(9)));
+        assert!(control.is_valid());
+
+        control.set_range(None);
+        assert!(control.start.has_err(REQUIRED_NAME));
+        assert!(!control.end.has_errs());
+
+        runtime.dispose();
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::{NaiveDate, NaiveTime};
+use leptos::{create_rw_signal, provide_context, use_context, RwSignal, SignalGet, SignalSet};
+
+use crate::file::format_size;
+
+use super::{
+    control::AbstractFormControl,
+    validator::{ValidatorKey, ValidatorMetadata},
+    validators::{
+        accept_type::ACCEPT_TYPE_NAME, date_range_order::DATE_RANGE_ORDER_NAME, email::EMAIL_NAME,
+        max_date::MAX_DATE_NAME, max_file_size::MAX_FILE_SIZE_NAME, max_length::MAX_LENGTH_NAME,
+        max_selected::MAX_SELECTED_NAME, max_time::MAX_TIME_NAME, min::MIN_NAME,
+        min_date::MIN_DATE_NAME, min_length::MIN_LENGTH_NAME, min_selected::MIN_SELECTED_NAME,
+        min_time::MIN_TIME_NAME, required::REQUIRED_NAME, required_true::REQUIRED_TRUE_NAME,
+    },
+};
+
+/// When the validation errors of a control become visible.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorVisibility {
+    /// once the user has left the control
+    #[default]
+    OnTouched,
+    /// as soon as the user changes the value
+    OnDirty,
+    /// only after the form has been submitted
+    OnSubmit,
+}
+
+/// Form wide settings of the validation feedback shown by the lumx inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormFeedback {
+    pub visibility: ErrorVisibility,
+    submitted: RwSignal<bool>,
+}
+
+impl FormFeedback {
+    pub fn new(visibility: ErrorVisibility) -> Self {
+        Self {
+            visibility,
+            submitted: create_rw_signal(false),
+        }
+    }
+
+    pub fn is_submitted(&self) -> bool {
+        self.submitted.get()
+    }
+
+    /// reveal the errors of every control, whatever the visibility rule
+    pub fn mark_as_submitted(&self) {
+        self.submitted.set(true);
+    }
+
+    pub fn reset_submitted(&self) {
+        self.submitted.set(false);
+    }
+
+    /// whether the errors of the control should be shown
+    pub fn show_errs<T, C>(&self, control: &C) -> bool
+    where
+        T: Clone + 'static,
+        C: AbstractFormControl<T>,
+    {
+        if control.is_valid() {
+            return false;
+        }
+
+        let interacted = match self.visibility {
+            ErrorVisibility::OnTouched => control.is_touched(),
+            ErrorVisibility::OnDirty => control.is_dirty(),
+            ErrorVisibility::OnSubmit => false,
+        };
+
+        interacted || self.is_submitted()
+    }
+}
+
+/// Provides the validation feedback settings to the lumx inputs of the current scope.
+pub fn provide_form_feedback(visibility: ErrorVisibility) -> FormFeedback {
+    let feedback = FormFeedback::new(visibility);
+    provide_context(feedback);
+
+    feedback
+}
+
+/// Returns the provided feedback settings, or the defaults when none were provided.
+pub fn use_form_feedback() -> FormFeedback {
+    use_context::<FormFeedback>().unwrap_or_else(|| FormFeedback::new(ErrorVisibility::default()))
+}
+
+/// ISO formatted date stored in the metadata as days from the common era.
+fn meta_date(meta: &ValidatorMetadata, key: &str) -> String {
+    meta.get(key)
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+/// `HH:MM` formatted time stored in the metadata as seconds from midnight.
+fn meta_time(meta: &ValidatorMetadata, key: &str) -> String {
+    meta.get(key)
+        .and_then(|seconds| NaiveTime::from_num_seconds_from_midnight_opt(seconds as u32, 0))
+        .map(|time| time.format("%H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Message shown for the errors of the built-in validators.
+pub fn default_err_message(key: &ValidatorKey, meta: &ValidatorMetadata) -> String {
+    match key.as_str() {
+        REQUIRED_NAME => String::from("This field is required."),
+        REQUIRED_TRUE_NAME => String::from("This box must be checked."),
+        EMAIL_NAME => String::from("Enter a valid email address."),
+        MIN_NAME => format!(
+            "The value must be at least {}.",
+            meta.get_number("min_val").unwrap_or_default()
+        ),
+        MIN_LENGTH_NAME => format!(
+            "Enter at least {} characters.",
+            meta.get("min_length").unwrap_or_default()
+        ),
+        MAX_LENGTH_NAME => format!(
+            "Enter at most {} characters.",
+            meta.get("max_length").unwrap_or_default()
+        ),
+        MIN_SELECTED_NAME => format!(
+            "Select at least {}.",
+            meta.get("min_selected").unwrap_or_default()
+        ),
+        MAX_SELECTED_NAME => format!(
+            "Select at most {}.",
+            meta.get("max_selected").unwrap_or_default()
+        ),
+        MIN_DATE_NAME => format!(
+            "The date must be on or after {}.",
+            meta_date(meta, "min_date")
+        ),
+        MAX_DATE_NAME => format!(
+            "The date must be on or before {}.",
+            meta_date(meta, "max_date")
+        ),
+        DATE_RANGE_ORDER_NAME => format!(
+            "The end date must be on or after {}.",
+            meta_date(meta, "start_date")
+        ),
+        MIN_TIME_NAME => format!(
+            "The time must be at or after {}.",
+            meta_time(meta, "min_time")
+        ),
+        MAX_TIME_NAME => format!(
+            "The time must be at or before {}.",
+            meta_time(meta, "max_time")
+        ),
+        ACCEPT_TYPE_NAME => String::from("This type of file is not accepted."),
+        MAX_FILE_SIZE_NAME => format!(
+            "Files must be at most {}.",
+            format_size(meta.get("max_size_kb").unwrap_or_default() as u64 * 1000)
+        ),
+        _ => String::from("The value is not valid."),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use leptos::create_runtime;
+
+    use crate::forms::{
+        control::{AbstractFormControl, FormControl},
+        feedback::{ErrorVisibility, FormFeedback},
+        validators::required::Required,
+    };
+
+    #[test]
+    fn it_shows_errs_following_the_visibility_rule() {
+        let runtime = create_runtime();
+
+        let on_touched = FormFeedback::new(ErrorVisibility::OnTouched);
+        let on_dirty = FormFeedback::new(ErrorVisibility::OnDirty);
+        let on_submit = FormFeedback::new(ErrorVisibility::OnSubmit);
+
+        let form_control: FormControl<String> =
+            FormControl::new(None, vec![Box::new(Required::new())]);
+
+        assert!(!on_touched.show_errs(&form_control));
+        assert!(!on_dirty.show_errs(&form_control));
+
+        form_control.mark_as_dirty();
+        assert!(!on_touched.show_errs(&form_control));
+        assert!(on_dirty.show_errs(&form_control));
+
+        form_control.mark_as_touched();
+        assert!(on_touched.show_errs(&form_control));
+        assert!(!on_submit.show_errs(&form_control));
+
+        on_submit.mark_as_submitted();
+        assert!(on_submit.show_errs(&form_control));
+
+        form_control.set_value(Some(String::from("john")));
+        assert!(!on_submit.show_errs(&form_control));
+
+        runtime.dispose();
+    }
+}
@@ -1,3 +1,5 @@
 pub mod control;
+pub mod date_range;
+pub mod feedback;
 pub mod validator;
 pub mod validators;
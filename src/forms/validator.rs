@@ -1,13 +1,21 @@
 use std::{cell::RefCell, collections::HashMap};
 
+use crate::number::step_precision;
+
 pub type ValidatorKey = String;
 pub type ControlValidatorResult = Result<(), (ValidatorKey, ValidatorMetadata)>;
 
 pub trait ControlValidator<T> {
     fn validate(&self, value: Option<T>) -> ControlValidatorResult;
+
+    /// name and settings of the rule, for the components showing it before it
+    /// fails, e.g. the limit of a character counter
+    fn constraint(&self) -> Option<(ValidatorKey, ValidatorMetadata)> {
+        None
+    }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
 pub struct ValidatorMetadata {
     meta: RefCell<HashMap<String, i32>>,
 }
@@ -24,9 +32,28 @@ impl ValidatorMetadata {
     }
 
     pub fn get(&self, key: &str) -> Option<i32> {
-        self.meta
-            .borrow()
-            .get(key)
-            .and_then(|val| Some(val.clone()))
+        self.meta.borrow().get(key).copied()
+    }
+
+    /// stores a decimal as `key` scaled by `10^{key}_decimals`, e.g. `125` and `2` for `1.25`
+    pub fn add_number(&self, key: String, value: f64) {
+        let mut decimals = step_precision(value).min(9) as i32;
+
+        while decimals > 0 && (value * 10f64.powi(decimals)).abs() > i32::MAX as f64 {
+            decimals -= 1;
+        }
+
+        let scaled = (value * 10f64.powi(decimals)).round();
+
+        self.add(format!("{key}_decimals"), decimals);
+        self.add(key, scaled.clamp(i32::MIN as f64, i32::MAX as f64) as i32);
+    }
+
+    /// decimal stored with `add_number`, or an integer stored with `add`
+    pub fn get_number(&self, key: &str) -> Option<f64> {
+        let decimals = self.get(&format!("{key}_decimals")).unwrap_or_default();
+
+        self.get(key)
+            .map(|scaled| scaled as f64 / 10f64.powi(decimals))
     }
 }
@@ -0,0 +1,44 @@
+use crate::{
+    file::{accepts, FileDescriptor},
+    forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata},
+};
+
+pub const ACCEPT_TYPE_NAME: &str = "ACCEPT_TYPE";
+
+/// Files are reported in the metadata by their position in the control value.
+pub struct AcceptType {
+    accept: String,
+}
+
+impl AcceptType {
+    /// `accept` follows the `accept` attribute of file inputs, e.g. `image/*,.pdf`,
+    /// see `file::accepts`.
+    pub fn new(accept: impl Into<String>) -> Self {
+        Self {
+            accept: accept.into(),
+        }
+    }
+}
+
+impl ControlValidator<Vec<FileDescriptor>> for AcceptType {
+    fn validate(&self, value: Option<Vec<FileDescriptor>>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            let rejected: Vec<usize> = val
+                .iter()
+                .enumerate()
+                .filter(|(_, file)| !accepts(&self.accept, &file.name, &file.mime_type))
+                .map(|(index, _)| index)
+                .collect();
+
+            if let Some(first) = rejected.first() {
+                let meta = ValidatorMetadata::new();
+                meta.add(String::from("file_index"), *first as i32);
+                meta.add(String::from("rejected_count"), rejected.len() as i32);
+
+                Err((String::from(ACCEPT_TYPE_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
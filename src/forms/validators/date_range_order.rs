@@ -0,0 +1,35 @@
+use chrono::{Datelike, NaiveDate};
+use leptos::{RwSignal, SignalGetUntracked};
+
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const DATE_RANGE_ORDER_NAME: &str = "DATE_RANGE_ORDER";
+
+/// Validates the end of a range against the value of its start, see `DateRangeControl`.
+///
+/// Dates are reported in the metadata as days from the common era.
+pub struct DateRangeOrder {
+    start: RwSignal<Option<NaiveDate>>,
+}
+
+impl DateRangeOrder {
+    pub fn new(start: RwSignal<Option<NaiveDate>>) -> Self {
+        Self { start }
+    }
+}
+
+impl ControlValidator<NaiveDate> for DateRangeOrder {
+    fn validate(&self, value: Option<NaiveDate>) -> ControlValidatorResult {
+        if let (Some(start), Some(end)) = (self.start.try_get_untracked().flatten(), value) {
+            if end.lt(&start) {
+                let meta = ValidatorMetadata::new();
+                meta.add(String::from("start_date"), start.num_days_from_ce());
+                meta.add(String::from("end_date"), end.num_days_from_ce());
+
+                Err((String::from(DATE_RANGE_ORDER_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
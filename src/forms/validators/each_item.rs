@@ -0,0 +1,36 @@
+use crate::forms::validator::{ControlValidator, ControlValidatorResult};
+
+/// Applies validators of a single value to every item of a list, e.g. `Email` to recipients.
+///
+/// The first failing item is reported with the key and metadata of the failing
+/// validator, its position added to the metadata as `item_index`.
+pub struct EachItem<T> {
+    validators: Vec<Box<dyn ControlValidator<T>>>,
+}
+
+impl<T> EachItem<T> {
+    pub fn new(validators: Vec<Box<dyn ControlValidator<T>>>) -> Self {
+        Self { validators }
+    }
+}
+
+impl<T> ControlValidator<Vec<T>> for EachItem<T>
+where
+    T: Clone,
+{
+    fn validate(&self, value: Option<Vec<T>>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            for (index, item) in val.into_iter().enumerate() {
+                for validator in self.validators.iter() {
+                    if let Err((key, meta)) = validator.validate(Some(item.clone())) {
+                        meta.add(String::from("item_index"), index as i32);
+
+                        Err((key, meta))?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
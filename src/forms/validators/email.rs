@@ -0,0 +1,44 @@
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const EMAIL_NAME: &str = "EMAIL";
+
+#[derive(Default)]
+pub struct Email;
+
+impl Email {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// loose check of `local@domain.tld`, empty values are left to `Required`.
+fn is_email(value: &str) -> bool {
+    if value.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        }
+        None => false,
+    }
+}
+
+impl ControlValidator<String> for Email {
+    fn validate(&self, value: Option<String>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            let val = val.trim();
+
+            if !val.is_empty() && !is_email(val) {
+                Err((String::from(EMAIL_NAME), ValidatorMetadata::new()))?
+            }
+        }
+
+        Ok(())
+    }
+}
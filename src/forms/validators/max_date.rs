@@ -0,0 +1,33 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const MAX_DATE_NAME: &str = "MAX_DATE";
+
+/// Dates are reported in the metadata as days from the common era,
+/// see `NaiveDate::from_num_days_from_ce_opt`.
+pub struct MaxDate {
+    max_date: NaiveDate,
+}
+
+impl MaxDate {
+    pub fn new(max_date: NaiveDate) -> Self {
+        Self { max_date }
+    }
+}
+
+impl ControlValidator<NaiveDate> for MaxDate {
+    fn validate(&self, value: Option<NaiveDate>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            if val.gt(&self.max_date) {
+                let meta = ValidatorMetadata::new();
+                meta.add(String::from("max_date"), self.max_date.num_days_from_ce());
+                meta.add(String::from("actual_date"), val.num_days_from_ce());
+
+                Err((String::from(MAX_DATE_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    file::FileDescriptor,
+    forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata},
+};
+
+pub const MAX_FILE_SIZE_NAME: &str = "MAX_FILE_SIZE";
+
+/// Sizes are reported in the metadata in kilobytes, rounded up, together
+/// with the position of the first file too large.
+pub struct MaxFileSize {
+    max_size: u64,
+}
+
+impl MaxFileSize {
+    /// `max_size` in bytes
+    pub fn new(max_size: u64) -> Self {
+        Self { max_size }
+    }
+}
+
+/// kilobytes fit sizes up to a couple of terabytes in the `i32` metadata
+fn meta_size(size: u64) -> i32 {
+    i32::try_from(size.div_ceil(1000)).unwrap_or(i32::MAX)
+}
+
+impl ControlValidator<Vec<FileDescriptor>> for MaxFileSize {
+    fn validate(&self, value: Option<Vec<FileDescriptor>>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            let too_large = val
+                .iter()
+                .enumerate()
+                .find(|(_, file)| file.size.gt(&self.max_size));
+
+            if let Some((index, file)) = too_large {
+                let meta = ValidatorMetadata::new();
+                meta.add(String::from("max_size_kb"), meta_size(self.max_size));
+                meta.add(String::from("actual_size_kb"), meta_size(file.size));
+                meta.add(String::from("file_index"), index as i32);
+
+                Err((String::from(MAX_FILE_SIZE_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::forms::validator::{
+    ControlValidator, ControlValidatorResult, ValidatorKey, ValidatorMetadata,
+};
+
+pub const MAX_LENGTH_NAME: &str = "MAX_LENGTH";
+
+pub struct MaxLength {
+    max_length: i32,
+}
+
+impl MaxLength {
+    pub fn new(max_length: i32) -> Self {
+        Self { max_length }
+    }
+}
+
+impl ControlValidator<String> for MaxLength {
+    fn validate(&self, value: Option<String>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            let val_len = val.chars().count() as i32;
+
+            if val_len.gt(&self.max_length) {
+                let meta = ValidatorMetadata::new();
+                meta.add(String::from("max_length"), self.max_length);
+                meta.add(String::from("actual_length"), val_len);
+
+                Err((String::from(MAX_LENGTH_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+
+    fn constraint(&self) -> Option<(ValidatorKey, ValidatorMetadata)> {
+        let meta = ValidatorMetadata::new();
+        meta.add(String::from("max_length"), self.max_length);
+
+        Some((String::from(MAX_LENGTH_NAME), meta))
+    }
+}
@@ -0,0 +1,34 @@
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const MAX_SELECTED_NAME: &str = "MAX_SELECTED";
+
+pub struct MaxSelected {
+    max_selected: i32,
+}
+
+impl MaxSelected {
+    pub fn new(max_selected: i32) -> Self {
+        Self { max_selected }
+    }
+}
+
+impl<V> ControlValidator<Vec<V>> for MaxSelected
+where
+    V: 'static,
+{
+    fn validate(&self, value: Option<Vec<V>>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            let selected = val.len() as i32;
+
+            if selected.gt(&self.max_selected) {
+                let meta = ValidatorMetadata::new();
+                meta.add(String::from("max_selected"), self.max_selected);
+                meta.add(String::from("actual_selected"), selected);
+
+                Err((String::from(MAX_SELECTED_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{NaiveTime, Timelike};
+
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const MAX_TIME_NAME: &str = "MAX_TIME";
+
+/// Times are reported in the metadata as seconds from midnight.
+pub struct MaxTime {
+    max_time: NaiveTime,
+}
+
+impl MaxTime {
+    pub fn new(max_time: NaiveTime) -> Self {
+        Self { max_time }
+    }
+}
+
+impl ControlValidator<NaiveTime> for MaxTime {
+    fn validate(&self, value: Option<NaiveTime>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            if val.gt(&self.max_time) {
+                let meta = ValidatorMetadata::new();
+                meta.add(
+                    String::from("max_time"),
+                    self.max_time.num_seconds_from_midnight() as i32,
+                );
+                meta.add(
+                    String::from("actual_time"),
+                    val.num_seconds_from_midnight() as i32,
+                );
+
+                Err((String::from(MAX_TIME_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,24 +1,31 @@
-use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+use crate::{
+    forms::validator::{ControlValidator, ControlValidatorResult, ValidatorKey, ValidatorMetadata},
+    number::NumberValue,
+};
 
 pub const MIN_NAME: &str = "MIN";
 
-pub struct Min {
-    min_value: i32,
+/// Values are reported in the metadata as numbers, see `ValidatorMetadata::get_number`.
+pub struct Min<T = i32> {
+    min_value: T,
 }
 
-impl Min {
-    pub fn new(min_value: i32) -> Self {
+impl<T> Min<T> {
+    pub fn new(min_value: T) -> Self {
         Self { min_value }
     }
 }
 
-impl ControlValidator<i32> for Min {
-    fn validate(&self, value: Option<i32>) -> ControlValidatorResult {
+impl<T> ControlValidator<T> for Min<T>
+where
+    T: NumberValue,
+{
+    fn validate(&self, value: Option<T>) -> ControlValidatorResult {
         if let Some(val) = value {
             if val.lt(&self.min_value) {
                 let meta = ValidatorMetadata::new();
-                meta.add(String::from("min_val"), self.min_value);
-                meta.add(String::from("actual_value"), val);
+                meta.add_number(String::from("min_val"), self.min_value.to_f64());
+                meta.add_number(String::from("actual_value"), val.to_f64());
 
                 Err((String::from(MIN_NAME), meta))?
             }
@@ -26,4 +33,11 @@ impl ControlValidator<i32> for Min {
 
         Ok(())
     }
+
+    fn constraint(&self) -> Option<(ValidatorKey, ValidatorMetadata)> {
+        let meta = ValidatorMetadata::new();
+        meta.add_number(String::from("min_val"), self.min_value.to_f64());
+
+        Some((String::from(MIN_NAME), meta))
+    }
 }
@@ -0,0 +1,33 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const MIN_DATE_NAME: &str = "MIN_DATE";
+
+/// Dates are reported in the metadata as days from the common era,
+/// see `NaiveDate::from_num_days_from_ce_opt`.
+pub struct MinDate {
+    min_date: NaiveDate,
+}
+
+impl MinDate {
+    pub fn new(min_date: NaiveDate) -> Self {
+        Self { min_date }
+    }
+}
+
+impl ControlValidator<NaiveDate> for MinDate {
+    fn validate(&self, value: Option<NaiveDate>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            if val.lt(&self.min_date) {
+                let meta = ValidatorMetadata::new();
+                meta.add(String::from("min_date"), self.min_date.num_days_from_ce());
+                meta.add(String::from("actual_date"), val.num_days_from_ce());
+
+                Err((String::from(MIN_DATE_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
-use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+use crate::forms::validator::{
+    ControlValidator, ControlValidatorResult, ValidatorKey, ValidatorMetadata,
+};
 
 pub const MIN_LENGTH_NAME: &str = "MIN_LENGTH";
 
@@ -15,7 +17,7 @@ impl MinLength {
 impl ControlValidator<String> for MinLength {
     fn validate(&self, value: Option<String>) -> ControlValidatorResult {
         if let Some(val) = value {
-            let val_len = val.trim().len() as i32;
+            let val_len = val.trim().chars().count() as i32;
 
             if val_len.lt(&self.min_length) {
                 let meta = ValidatorMetadata::new();
@@ -28,4 +30,11 @@ impl ControlValidator<String> for MinLength {
 
         Ok(())
     }
+
+    fn constraint(&self) -> Option<(ValidatorKey, ValidatorMetadata)> {
+        let meta = ValidatorMetadata::new();
+        meta.add(String::from("min_length"), self.min_length);
+
+        Some((String::from(MIN_LENGTH_NAME), meta))
+    }
 }
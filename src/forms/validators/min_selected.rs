@@ -0,0 +1,32 @@
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const MIN_SELECTED_NAME: &str = "MIN_SELECTED";
+
+pub struct MinSelected {
+    min_selected: i32,
+}
+
+impl MinSelected {
+    pub fn new(min_selected: i32) -> Self {
+        Self { min_selected }
+    }
+}
+
+impl<V> ControlValidator<Vec<V>> for MinSelected
+where
+    V: 'static,
+{
+    fn validate(&self, value: Option<Vec<V>>) -> ControlValidatorResult {
+        let selected = value.map_or(0, |val| val.len() as i32);
+
+        if selected.lt(&self.min_selected) {
+            let meta = ValidatorMetadata::new();
+            meta.add(String::from("min_selected"), self.min_selected);
+            meta.add(String::from("actual_selected"), selected);
+
+            Err((String::from(MIN_SELECTED_NAME), meta))?
+        }
+
+        Ok(())
+    }
+}
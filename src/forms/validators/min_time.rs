@@ -0,0 +1,38 @@
+use chrono::{NaiveTime, Timelike};
+
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const MIN_TIME_NAME: &str = "MIN_TIME";
+
+/// Times are reported in the metadata as seconds from midnight.
+pub struct MinTime {
+    min_time: NaiveTime,
+}
+
+impl MinTime {
+    pub fn new(min_time: NaiveTime) -> Self {
+        Self { min_time }
+    }
+}
+
+impl ControlValidator<NaiveTime> for MinTime {
+    fn validate(&self, value: Option<NaiveTime>) -> ControlValidatorResult {
+        if let Some(val) = value {
+            if val.lt(&self.min_time) {
+                let meta = ValidatorMetadata::new();
+                meta.add(
+                    String::from("min_time"),
+                    self.min_time.num_seconds_from_midnight() as i32,
+                );
+                meta.add(
+                    String::from("actual_time"),
+                    val.num_seconds_from_midnight() as i32,
+                );
+
+                Err((String::from(MIN_TIME_NAME), meta))?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,16 @@
+pub mod accept_type;
+pub mod date_range_order;
+pub mod each_item;
+pub mod email;
+pub mod max_date;
+pub mod max_file_size;
+pub mod max_length;
+pub mod max_selected;
+pub mod max_time;
 pub mod min;
+pub mod min_date;
 pub mod min_length;
+pub mod min_selected;
+pub mod min_time;
 pub mod required;
+pub mod required_true;
@@ -2,6 +2,7 @@ use crate::forms::validator::{ControlValidator, ControlValidatorResult, Validato
 
 pub const REQUIRED_NAME: &str = "REQUIRED";
 
+#[derive(Default)]
 pub struct Required;
 
 impl Required {
@@ -0,0 +1,23 @@
+use crate::forms::validator::{ControlValidator, ControlValidatorResult, ValidatorMetadata};
+
+pub const REQUIRED_TRUE_NAME: &str = "REQUIRED_TRUE";
+
+/// Fails unless the value is `true`, e.g. for a checkbox accepting the terms.
+#[derive(Default)]
+pub struct RequiredTrue;
+
+impl RequiredTrue {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ControlValidator<bool> for RequiredTrue {
+    fn validate(&self, value: Option<bool>) -> ControlValidatorResult {
+        if value != Some(true) {
+            Err((String::from(REQUIRED_TRUE_NAME), ValidatorMetadata::new()))?
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+//! Case and accent insensitive highlighting of a query in a text, e.g. the typed
+//! text in the labels of suggestions.
+
+use leptos::{component, view, CollectView, IntoView};
+
+/// accented latin letters and the letter they are searched as
+const ACCENTED: [(&str, char); 19] = [
+    ("àáâãäåāăą", 'a'),
+    ("çćĉċč", 'c'),
+    ("ďđ", 'd'),
+    ("èéêëēĕėęě", 'e'),
+    ("ĝğġģ", 'g'),
+    ("ĥħ", 'h'),
+    ("ìíîïĩīĭįı", 'i'),
+    ("ĵ", 'j'),
+    ("ķ", 'k'),
+    ("ĺļľŀł", 'l'),
+    ("ñńņňŉ", 'n'),
+    ("òóôõöøōŏő", 'o'),
+    ("ŕŗř", 'r'),
+    ("śŝşš", 's'),
+    ("ţťŧ", 't'),
+    ("ùúûüũūŭůűų", 'u'),
+    ("ŵ", 'w'),
+    ("ýÿŷ", 'y'),
+    ("źżž", 'z'),
+];
+
+/// Lower case letter without its accent, one char for one char so positions are kept.
+pub fn fold_char(ch: char) -> char {
+    let lower = ch.to_lowercase().next().unwrap_or(ch);
+
+    if lower.is_ascii() {
+        return lower;
+    }
+
+    ACCENTED
+        .iter()
+        .find(|(accented, _)| accented.contains(lower))
+        .map_or(lower, |(_, base)| *base)
+}
+
+/// Text folded by `fold_char`, e.g. `Ångström` is searched as `angstrom`.
+pub fn fold(text: &str) -> String {
+    text.chars().map(fold_char).collect()
+}
+
+/// Splits the text into the runs matching the query or not, in order,
+/// each run flagged `true` when it matches.
+pub fn highlight_matches(text: &str, query: &str) -> Vec<(String, bool)> {
+    let chars: Vec<char> = text.chars().collect();
+    let folded: Vec<char> = chars.iter().copied().map(fold_char).collect();
+    let query: Vec<char> = query.trim().chars().map(fold_char).collect();
+
+    let mut runs: Vec<(String, bool)> = Vec::new();
+    let mut push = |run: &[char], matched: bool| {
+        if !run.is_empty() {
+            runs.push((run.iter().collect(), matched));
+        }
+    };
+
+    if query.is_empty() {
+        push(&chars, false);
+        return runs;
+    }
+
+    let (mut start, mut idx) = (0, 0);
+
+    while idx + query.len() <= folded.len() {
+        if folded[idx..idx + query.len()] == query[..] {
+            push(&chars[start..idx], false);
+            push(&chars[idx..idx + query.len()], true);
+            idx += query.len();
+            start = idx;
+        } else {
+            idx += 1;
+        }
+    }
+
+    push(&chars[start..], false);
+
+    runs
+}
+
+/// Text with the occurrences of the query marked.
+#[component]
+pub fn Highlight(#[prop(into)] text: String, #[prop(into)] query: String) -> impl IntoView {
+    highlight_matches(&text, &query)
+        .into_iter()
+        .map(|(run, matched)| match matched {
+            true => view! {
+                <mark class="lumx-highlight bg-transparent font-semibold text-inherit">{run}</mark>
+            }
+            .into_view(),
+            false => run.into_view(),
+        })
+        .collect_view()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::highlight::{fold, highlight_matches};
+
+    #[test]
+    fn it_folds_case_and_accents() {
+        assert_eq!(fold("Ångström"), "angstrom");
+        assert_eq!(fold("Łódź"), "lodz");
+        assert_eq!(fold("ŠKODA"), "skoda");
+    }
+
+    #[test]
+    fn it_highlights_every_match() {
+        assert_eq!(
+            highlight_matches("Banana", "an"),
+            vec![
+                (String::from("B"), false),
+                (String::from("an"), true),
+                (String::from("an"), true),
+                (String::from("a"), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_highlights_regardless_of_case_and_accents() {
+        assert_eq!(
+            highlight_matches("Crème Brûlée", "brulee"),
+            vec![
+                (String::from("Crème "), false),
+                (String::from("Brûlée"), true)
+            ]
+        );
+        assert_eq!(
+            highlight_matches("Zürich", ""),
+            vec![(String::from("Zürich"), false)]
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use leptos::{component, view, IntoView};
+
+#[component]
+pub fn ArrowUpTray(#[prop()] class: &'static str) -> impl IntoView {
+    view! {
+        <svg class=class xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" >
+            <path stroke-linecap="round" stroke-linejoin="round" d="M3 16.5v2.25A2.25 2.25 0 0 0 5.25 21h13.5A2.25 2.25 0 0 0 21 18.75V16.5m-13.5-9L12 3m0 0 4.5 4.5M12 3v13.5" />
+        </svg>
+    }
+}
@@ -0,0 +1,10 @@
+use leptos::{component, view, IntoView};
+
+#[component]
+pub fn Bold(#[prop()] class: &'static str) -> impl IntoView {
+    view! {
+        <svg class=class xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="2" stroke="currentColor" >
+            <path stroke-linecap="round" stroke-linejoin="round" d="M6.75 4.5h6a3.75 3.75 0 0 1 0 7.5h-6V4.5Zm0 7.5h7.125a3.75 3.75 0 0 1 0 7.5H6.75V12Z" />
+        </svg>
+    }
+}
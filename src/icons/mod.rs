@@ -1,2 +1,22 @@
+pub mod arrow_up_tray;
+pub mod bold;
+pub mod calendar_days;
+pub mod check;
+pub mod chevron_double_left;
+pub mod chevron_double_right;
+pub mod chevron_left;
+pub mod chevron_right;
+pub mod chevron_up_down;
+pub mod clipboard;
+pub mod clock;
+pub mod document;
+pub mod eye;
+pub mod eye_slash;
+pub mod italic;
+pub mod link;
+pub mod list_bullet;
+pub mod minus;
+pub mod numbered_list;
+pub mod plus;
 pub mod spin;
 pub mod x_mark;
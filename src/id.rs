@@ -0,0 +1,115 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use leptos::{logging::debug_warn, provide_context, use_context};
+
+/// fallback counter used when no [`IdGenerator`] was provided,
+/// ids produced by it are unique but not stable between server and client.
+static FALLBACK_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Hands out element ids from a counter scoped to the context where it was provided,
+/// so a tree rendered on the server and hydrated on the client gets the same ids.
+#[derive(Debug, Clone, Default)]
+pub struct IdGenerator {
+    next: Rc<Cell<usize>>,
+}
+
+impl IdGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_id(&self, prefix: &str) -> String {
+        let id = self.next.get();
+        self.next.set(id + 1);
+
+        format!("lumx-{prefix}-{id}")
+    }
+}
+
+/// Provides a fresh [`IdGenerator`] to the current scope,
+/// call it once at the root of the app both on the server and on the client.
+pub fn provide_id_generator() {
+    provide_context(IdGenerator::new());
+}
+
+/// Returns the next id for an element of the given kind.
+pub fn use_id(prefix: &str) -> String {
+    match use_context::<IdGenerator>() {
+        Some(generator) => generator.next_id(prefix),
+        None => {
+            debug_warn!(
+                "lumx: no IdGenerator found, call `provide_id_generator` at the root of the app \
+                 to keep ids stable between server and client"
+            );
+
+            let id = FALLBACK_ID.fetch_add(1, Ordering::Relaxed);
+            format!("lumx-{prefix}-x{id}")
+        }
+    }
+}
+
+/// Uses the explicit id when given, otherwise generates one.
+pub fn resolve_id(id: Option<String>, prefix: &str) -> String {
+    id.unwrap_or_else(|| use_id(prefix))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use leptos::{create_runtime, ssr::render_to_string, view};
+
+    use crate::{
+        forms::control::FormControl,
+        id::{provide_id_generator, resolve_id},
+        input_text::InputText,
+    };
+
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn render_form() -> String {
+        render_to_string(|| {
+            provide_id_generator();
+
+            let name = Arc::new(FormControl::<String>::new(None, vec![]));
+            let email = Arc::new(FormControl::<String>::new(None, vec![]));
+
+            view! {
+                <form>
+                    <InputText label="name" control=name />
+                    <InputText label="email" control=email />
+                </form>
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn it_generates_the_same_ids_on_every_render() {
+        let first_render = render_form();
+        let second_render = render_form();
+
+        assert!(first_render.contains("id=\"lumx-input-text-0\""));
+        assert!(first_render.contains("for=\"lumx-input-text-0\""));
+        assert!(first_render.contains("id=\"lumx-input-text-1\""));
+        assert_eq!(first_render, second_render);
+    }
+
+    #[test]
+    fn it_prefers_the_explicit_id() {
+        let runtime = create_runtime();
+
+        provide_id_generator();
+
+        assert_eq!(
+            resolve_id(Some(String::from("email")), "input-text"),
+            "email"
+        );
+        assert_eq!(resolve_id(None, "input-text"), "lumx-input-text-0");
+
+        runtime.dispose();
+    }
+}
@@ -1,46 +1,377 @@
-use crate::forms::control::{AbstractFormControl, FormControl};
-use leptos::{component, event_target_value, view, IntoView, SignalGetUntracked};
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ControlValidator, ValidatorKey, ValidatorMetadata},
+        validators::email::Email,
+    },
+    icons::{clipboard::Clipboard, eye::Eye, eye_slash::EyeSlash, x_mark::XMark},
+    id::resolve_id,
+    mask::{Mask, MaskValue},
+};
+use leptos::{
+    component, create_effect, create_node_ref, create_signal, ev::MouseEvent, event_target_value,
+    html::Input, slot, view, Callback, ChildrenFn, IntoView, Show, SignalGet, SignalGetUntracked,
+    SignalUpdate, SignalWith,
+};
+use leptos_use::{use_clipboard, UseClipboardReturn};
 use std::sync::Arc;
-use uuid::Uuid;
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-input-text flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InputType {
+    #[default]
+    Text,
+    Email,
+    Password,
+    Number,
+    Search,
+    Tel,
+    Url,
+}
+
+impl InputType {
+    /// value of the `type` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputType::Text => "text",
+            InputType::Email => "email",
+            InputType::Password => "password",
+            InputType::Number => "number",
+            InputType::Search => "search",
+            InputType::Tel => "tel",
+            InputType::Url => "url",
+        }
+    }
+
+    /// virtual keyboard hint for mobile devices
+    pub fn input_mode(&self) -> &'static str {
+        match self {
+            InputType::Text | InputType::Password => "text",
+            InputType::Email => "email",
+            InputType::Number => "decimal",
+            InputType::Search => "search",
+            InputType::Tel => "tel",
+            InputType::Url => "url",
+        }
+    }
+
+    /// default `autocomplete` hint, can be overridden through the `autocomplete` prop
+    pub fn autocomplete(&self) -> &'static str {
+        match self {
+            InputType::Text => "on",
+            InputType::Email => "email",
+            InputType::Password => "current-password",
+            InputType::Number | InputType::Search => "off",
+            InputType::Tel => "tel",
+            InputType::Url => "url",
+        }
+    }
+
+    /// validators implied by the input type, to be passed when building the control.
+    pub fn validators(&self) -> Vec<Box<dyn ControlValidator<String>>> {
+        match self {
+            InputType::Email => vec![Box::new(Email::new())],
+            _ => vec![],
+        }
+    }
+
+    /// trailing action used when none is given
+    pub fn default_action(&self) -> Option<InputAction> {
+        match self {
+            InputType::Search => Some(InputAction::Clear),
+            InputType::Password => Some(InputAction::Reveal),
+            _ => None,
+        }
+    }
+
+    /// whether the trailing action applies, only a password can be revealed
+    pub fn supports(&self, action: InputAction) -> bool {
+        match action {
+            InputAction::Reveal => *self == InputType::Password,
+            InputAction::Clear | InputAction::Copy => true,
+        }
+    }
+}
+
+/// Button rendered at the end of the field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputAction {
+    /// empty the control, shown only while it has a value
+    Clear,
+    /// copy the current value to the clipboard
+    Copy,
+    /// toggle the visibility of a password, ignored for the other input types
+    Reveal,
+}
+
+/// Content placed before the input, e.g. an icon or a currency symbol.
+#[slot]
+pub struct InputTextPrefix {
+    children: ChildrenFn,
+}
+
+/// Content placed after the input, e.g. a unit or a button.
+#[slot]
+pub struct InputTextSuffix {
+    children: ChildrenFn,
+}
 
 #[component]
 pub fn InputText(
     #[prop(default = "label")] label: &'static str,
     #[prop(default = "")] placeholder: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] input_type: InputType,
+    #[prop(optional)] autocomplete: Option<&'static str>,
+    /// `step`, `min` and `max` only apply to [`InputType::Number`]
+    #[prop(optional)]
+    step: Option<f64>,
+    #[prop(optional)] min: Option<f64>,
+    #[prop(optional)] max: Option<f64>,
+    /// overrides the action implied by the input type
+    #[prop(optional)]
+    action: Option<InputAction>,
+    /// formats the value as the user types, see [`Mask`] for the pattern language
+    #[prop(optional, into)]
+    mask: Option<Mask>,
+    /// whether a masked field hands the masked or the raw value to the control
+    #[prop(optional)]
+    mask_value: MaskValue,
+    #[prop(optional)] input_text_prefix: Option<InputTextPrefix>,
+    #[prop(optional)] input_text_suffix: Option<InputTextSuffix>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
     control: Arc<FormControl<String>>,
 ) -> impl IntoView {
-    let form_id = Uuid::new_v4().to_string();
+    let form_id = resolve_id(id, "input-text");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let input_ref = create_node_ref::<Input>();
+    let control_value = control.value;
 
-    // notify the new value when the element loses focus
-    let on_blur = {
+    let action = action
+        .or(input_type.default_action())
+        .filter(|action| input_type.supports(*action));
+    let (revealed, revealed_writer) = create_signal(false);
+
+    let (step, min, max) = match input_type {
+        InputType::Number => (step, min, max),
+        _ => (None, None, None),
+    };
+
+    // notify the new value to the control while the user types
+    let on_input = {
         let control_ref = Arc::clone(&control);
+        let mask = mask.clone();
 
         move |ev| {
-            let input_value = event_target_value(&ev);
+            let mut input_value = event_target_value(&ev);
+
+            // format while typing, keeping the caret on the same logical position
+            if let (Some(mask), Some(input)) = (&mask, input_ref.get_untracked()) {
+                let caret = match input.selection_start() {
+                    Ok(Some(caret)) => char_index(&input_value, caret as usize),
+                    _ => input_value.chars().count(),
+                };
+
+                let (formatted, caret) = mask.format_with_caret(&input_value, caret);
+                let caret = utf16_index(&formatted.masked, caret) as u32;
+
+                input.set_value(&formatted.masked);
+                let _ = input.set_selection_range(caret, caret);
+
+                input_value = match mask_value {
+                    MaskValue::Masked => formatted.masked,
+                    MaskValue::Raw => formatted.raw,
+                };
+            }
+
+            control_ref.mark_as_dirty();
 
             if input_value.is_empty() {
                 control_ref.set_value(None);
             } else {
-                control_ref.set_value(Some(event_target_value(&ev)));
+                control_ref.set_value(Some(input_value));
             }
         }
     };
 
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    let on_clear = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: MouseEvent| {
+            control_ref.set_value(None);
+
+            if let Some(input) = input_ref.get_untracked() {
+                let _ = input.focus();
+            }
+        }
+    };
+
+    let on_toggle_reveal = move |_ev: MouseEvent| {
+        revealed_writer.update(|revealed| *revealed = !*revealed);
+    };
+
+    // value shown in the element, masked when a mask is set
+    let display_value = move |value: Option<String>| {
+        let value = value.unwrap_or_default();
+
+        match (&mask, mask_value) {
+            (Some(mask), MaskValue::Masked) => mask.format(&value).masked,
+            (Some(mask), MaskValue::Raw) => mask.format_raw(&value).masked,
+            (None, _) => value,
+        }
+    };
+
+    // reflect control changes (reset, load, undo) into the element, only
+    // writing when the value differs so the caret is not moved while typing.
+    create_effect({
+        let display_value = display_value.clone();
+
+        move |_| {
+            let value = display_value(control_value.get());
+
+            if let Some(input) = input_ref.get() {
+                if input.value() != value {
+                    input.set_value(&value);
+                }
+            }
+        }
+    });
+
+    // a revealed password is shown as plain text
+    let type_attr = move || match input_type {
+        InputType::Password if revealed.get() => "text",
+        _ => input_type.as_str(),
+    };
+
+    // field specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the input while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
     view! {
-        <div class="lumx-input-text flex flex-col rounded-md border box-border border-slate-200 p-2.5 bg-white mb-2.5 focus-within:border focus-within:border-blue-600">
+        <div class=class_props_by_state>
             <label for=form_id.clone() class="text-xs text-slate-900">{label}</label>
-            <input
-                class="outline-none text-sm bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
-                type="text"
-                placeholder=placeholder
-                id=form_id
-                on:blur=on_blur
-                prop:value={
-                    let control_ref = Arc::clone(&control);
-                    let control_value = control_ref.value.get_untracked();
-
-                    control_value.unwrap_or(String::from(""))
-                } />
+            <div class="lumx-input-text-field flex flex-row items-center gap-x-1">
+                {input_text_prefix.map(|prefix| view! {
+                    <span class="lumx-input-text-prefix shrink-0 flex items-center text-sm text-slate-500">
+                        {(prefix.children)()}
+                    </span>
+                })}
+
+                <input
+                    node_ref=input_ref
+                    class="grow min-w-0 outline-none text-sm bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
+                    type=type_attr
+                    inputmode=input_type.input_mode()
+                    autocomplete=autocomplete.unwrap_or(input_type.autocomplete())
+                    step=step
+                    min=min
+                    max=max
+                    placeholder=placeholder
+                    id=form_id
+                    aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                    aria-describedby=described_by
+                    on:input=on_input
+                    on:blur=on_blur
+                    value=display_value(control_value.get_untracked()) />
+
+                {input_text_suffix.map(|suffix| view! {
+                    <span class="lumx-input-text-suffix shrink-0 flex items-center text-sm text-slate-500">
+                        {(suffix.children)()}
+                    </span>
+                })}
+
+                {match action {
+                    Some(InputAction::Clear) => view! {
+                        <Show when=move || control_value.get().is_some()>
+                            <button type="button"
+                                aria-label="clear"
+                                on:click=on_clear.clone()
+                                class="lumx-input-text-clear cursor-pointer rounded-full hover:bg-slate-200">
+                                <XMark class="w-5 h-5 text-slate-900" />
+                            </button>
+                        </Show>
+                    }.into_view(),
+                    Some(InputAction::Copy) => {
+                        let UseClipboardReturn { is_supported, copy, .. } = use_clipboard();
+
+                        let on_copy = move |_ev: MouseEvent| {
+                            if let Some(value) = control_value.get_untracked() {
+                                copy(&value);
+                            }
+                        };
+
+                        view! {
+                            <button type="button"
+                                aria-label="copy"
+                                disabled=move || !is_supported.get()
+                                on:click=on_copy
+                                class="lumx-input-text-copy cursor-pointer rounded-full p-0.5 hover:bg-slate-200">
+                                <Clipboard class="w-4 h-4 text-slate-900" />
+                            </button>
+                        }.into_view()
+                    }
+                    Some(InputAction::Reveal) => view! {
+                        <button type="button"
+                            aria-label=move || if revealed.get() { "hide password" } else { "show password" }
+                            aria-pressed=move || revealed.get().to_string()
+                            on:click=on_toggle_reveal
+                            class="lumx-input-text-reveal cursor-pointer rounded-full p-0.5 hover:bg-slate-200">
+                            <Show
+                                when=move || revealed.get()
+                                fallback=|| view! { <Eye class="w-4 h-4 text-slate-900" /> }>
+                                <EyeSlash class="w-4 h-4 text-slate-900" />
+                            </Show>
+                        </button>
+                    }.into_view(),
+                    None => ().into_view(),
+                }}
+            </div>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
         </div>
     }
 }
+
+/// converts a UTF-16 offset, as used by the DOM selection api, into a char index
+fn char_index(value: &str, utf16_offset: usize) -> usize {
+    let mut offset = 0;
+
+    value
+        .chars()
+        .take_while(|ch| {
+            offset += ch.len_utf16();
+            offset <= utf16_offset
+        })
+        .count()
+}
+
+/// converts a char index into a UTF-16 offset for the DOM selection api
+fn utf16_index(value: &str, char_index: usize) -> usize {
+    value.chars().take(char_index).map(char::len_utf16).sum()
+}
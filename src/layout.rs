@@ -0,0 +1,20 @@
+//! Layout settings shared by the components.
+
+/// Direction the parts of a component are laid out in, e.g. the options of a
+/// radio group or the track of a slider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    /// value of `aria-orientation`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        }
+    }
+}
@@ -1,7 +1,36 @@
 pub mod button;
+pub mod calendar;
 pub mod card;
+pub mod checkbox;
+pub mod chip;
+pub mod date_picker;
+pub mod date_range_picker;
 pub mod dialog;
+pub mod field_feedback;
+pub mod file;
+pub mod file_upload;
 pub mod forms;
+pub mod highlight;
 pub mod icons;
+pub mod id;
 pub mod input_text;
+pub mod layout;
+pub mod listbox;
+pub mod mask;
+pub mod matcher;
+pub mod multi_select;
+pub mod number;
+pub mod number_input;
+pub mod otp_input;
+pub mod popover;
+pub mod radio_group;
+pub mod rich_text;
+pub mod rich_text_editor;
+pub mod select;
+pub mod slider;
+pub mod switch;
+pub mod tag_input;
+pub mod text_area;
+pub mod time;
+pub mod time_picker;
 pub mod type_ahead;
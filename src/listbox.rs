@@ -0,0 +1,135 @@
+//! Active option navigation shared by the list based inputs, following the WAI-ARIA listbox pattern.
+
+/// options moved by `PageUp` and `PageDown`
+pub const PAGE_SIZE: usize = 10;
+
+/// First option that is not disabled.
+pub fn first_enabled(disabled: &[bool]) -> Option<usize> {
+    disabled.iter().position(|disabled| !disabled)
+}
+
+/// Last option that is not disabled.
+pub fn last_enabled(disabled: &[bool]) -> Option<usize> {
+    disabled.iter().rposition(|disabled| !disabled)
+}
+
+/// Moves `delta` enabled options away from the active one, stopping at the ends of the list.
+/// Without an active option it starts from the first or the last option depending on the direction.
+pub fn move_active(disabled: &[bool], active: Option<usize>, delta: isize) -> Option<usize> {
+    let Some(active) = active.filter(|active| *active < disabled.len()) else {
+        return match delta < 0 {
+            true => last_enabled(disabled),
+            false => first_enabled(disabled),
+        };
+    };
+
+    let mut target = active;
+    let mut remaining = delta.unsigned_abs();
+    let mut idx = active;
+
+    while remaining > 0 {
+        idx = match delta < 0 {
+            true if idx == 0 => break,
+            true => idx - 1,
+            false if idx + 1 >= disabled.len() => break,
+            false => idx + 1,
+        };
+
+        if !disabled[idx] {
+            target = idx;
+            remaining -= 1;
+        }
+    }
+
+    Some(target)
+}
+
+/// Moves to the next or previous enabled option, wrapping around the ends of the list
+/// as the radio group pattern does.
+pub fn cycle_active(disabled: &[bool], active: Option<usize>, delta: isize) -> Option<usize> {
+    let Some(active) = active.filter(|active| *active < disabled.len()) else {
+        return move_active(disabled, None, delta);
+    };
+
+    let len = disabled.len() as isize;
+
+    (1..=len)
+        .map(|offset| (active as isize + offset * delta.signum()).rem_euclid(len) as usize)
+        .find(|idx| !disabled[*idx])
+}
+
+/// Next enabled option whose label starts with the typed characters,
+/// searching after the active option and wrapping around.
+pub fn match_typed(
+    labels: &[String],
+    disabled: &[bool],
+    typed: &str,
+    active: Option<usize>,
+) -> Option<usize> {
+    let typed = typed.to_lowercase();
+
+    if typed.is_empty() || labels.is_empty() {
+        return None;
+    }
+
+    // a repeated single character cycles through the options starting with it
+    let start = match active {
+        Some(active) if typed.chars().count() == 1 => active + 1,
+        Some(active) => active,
+        None => 0,
+    };
+
+    (0..labels.len())
+        .map(|offset| (start + offset) % labels.len())
+        .find(|idx| !disabled[*idx] && labels[*idx].to_lowercase().starts_with(&typed))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::listbox::{cycle_active, first_enabled, last_enabled, match_typed, move_active};
+
+    #[test]
+    fn it_finds_the_enabled_ends() {
+        let disabled = [true, false, false, true];
+
+        assert_eq!(first_enabled(&disabled), Some(1));
+        assert_eq!(last_enabled(&disabled), Some(2));
+        assert_eq!(first_enabled(&[true, true]), None);
+    }
+
+    #[test]
+    fn it_moves_skipping_disabled_options() {
+        let disabled = [false, true, false, false, true];
+
+        assert_eq!(move_active(&disabled, Some(0), 1), Some(2));
+        assert_eq!(move_active(&disabled, Some(2), -1), Some(0));
+        assert_eq!(move_active(&disabled, Some(3), 1), Some(3));
+        assert_eq!(move_active(&disabled, Some(0), -1), Some(0));
+        assert_eq!(move_active(&disabled, Some(0), 10), Some(3));
+        assert_eq!(move_active(&disabled, None, 1), Some(0));
+        assert_eq!(move_active(&disabled, None, -1), Some(3));
+    }
+
+    #[test]
+    fn it_cycles_skipping_disabled_options() {
+        let disabled = [false, true, false, true];
+
+        assert_eq!(cycle_active(&disabled, Some(0), 1), Some(2));
+        assert_eq!(cycle_active(&disabled, Some(2), 1), Some(0));
+        assert_eq!(cycle_active(&disabled, Some(0), -1), Some(2));
+        assert_eq!(cycle_active(&disabled, None, 1), Some(0));
+        assert_eq!(cycle_active(&[true, true], Some(0), 1), None);
+    }
+
+    #[test]
+    fn it_matches_typed_characters() {
+        let labels = ["Draft", "Done", "Archived", "Deleted"].map(String::from);
+        let disabled = [false, false, false, true];
+
+        assert_eq!(match_typed(&labels, &disabled, "d", None), Some(0));
+        assert_eq!(match_typed(&labels, &disabled, "d", Some(0)), Some(1));
+        assert_eq!(match_typed(&labels, &disabled, "d", Some(1)), Some(0));
+        assert_eq!(match_typed(&labels, &disabled, "do", Some(0)), Some(1));
+        assert_eq!(match_typed(&labels, &disabled, "x", None), None);
+    }
+}
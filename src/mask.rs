@@ -0,0 +1,326 @@
+//! Formatting of free text against a pattern such as `(999) 999-9999`.
+//!
+//! Pattern language:
+//! - `9` a digit
+//! - `a` a letter
+//! - `*` a letter or a digit
+//! - `\` escapes the next character so it is taken as a literal
+//! - `[` `]` wrap an optional segment, its slots are skipped when the input does not fit them
+//! - any other character is a literal inserted by the mask
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SlotKind {
+    Digit,
+    Letter,
+    AlphaNumeric,
+}
+
+impl SlotKind {
+    fn accepts(&self, ch: char) -> bool {
+        match self {
+            SlotKind::Digit => ch.is_ascii_digit(),
+            SlotKind::Letter => ch.is_alphabetic(),
+            SlotKind::AlphaNumeric => ch.is_alphanumeric(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Slot(SlotKind),
+    Literal(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MaskToken {
+    kind: TokenKind,
+    optional: bool,
+}
+
+/// Result of running a value through a [`Mask`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MaskedValue {
+    /// value with the literals of the mask, as displayed
+    pub masked: String,
+    /// only the characters typed into the slots
+    pub raw: String,
+    /// every required slot has been filled
+    pub complete: bool,
+}
+
+/// Which value a masked input hands to its control.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MaskValue {
+    #[default]
+    Masked,
+    Raw,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    tokens: Vec<MaskToken>,
+}
+
+impl Mask {
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut optional = false;
+        let mut chars = pattern.chars();
+
+        while let Some(ch) = chars.next() {
+            let kind = match ch {
+                '[' => {
+                    optional = true;
+                    continue;
+                }
+                ']' => {
+                    optional = false;
+                    continue;
+                }
+                '9' => TokenKind::Slot(SlotKind::Digit),
+                'a' => TokenKind::Slot(SlotKind::Letter),
+                '*' => TokenKind::Slot(SlotKind::AlphaNumeric),
+                '\\' => match chars.next() {
+                    Some(escaped) => TokenKind::Literal(escaped),
+                    None => break,
+                },
+                literal => TokenKind::Literal(literal),
+            };
+
+            tokens.push(MaskToken { kind, optional });
+        }
+
+        Self { tokens }
+    }
+
+    /// Formats a value as typed in the field, the literals of the mask it already
+    /// contains being kept in place. Characters that do not fit the pattern are dropped.
+    pub fn format(&self, value: &str) -> MaskedValue {
+        self.apply(value, true)
+    }
+
+    /// Formats a raw value, e.g. the one held by a control bound with `MaskValue::Raw`.
+    /// Unlike `format`, a character equal to a literal fills the next slot instead of
+    /// standing for the literal, so `+1 (999)` formats `123` as `+1 (123`.
+    pub fn format_raw(&self, value: &str) -> MaskedValue {
+        self.apply(value, false)
+    }
+
+    fn apply(&self, value: &str, consume_literals: bool) -> MaskedValue {
+        let mut input = value.chars().peekable();
+        let mut masked = String::new();
+        let mut raw = String::new();
+        // length of `masked` after the last filled slot, used to drop trailing literals
+        let mut filled_len = 0;
+        let mut complete = true;
+
+        // the literals leading the mask are only taken from a value starting with them,
+        // so `1` typed into `+1 (999)` fills the first slot
+        let prefix_len = self.literal_prefix().count();
+        let leading = match consume_literals {
+            true => self
+                .literal_prefix()
+                .zip(value.chars())
+                .take_while(|(literal, ch)| literal == ch)
+                .count(),
+            false => 0,
+        };
+
+        'tokens: for (idx, token) in self.tokens.iter().enumerate() {
+            match token.kind {
+                TokenKind::Literal(literal) => {
+                    if input.peek().is_none() {
+                        complete &= token.optional;
+                        continue;
+                    }
+
+                    masked.push(literal);
+
+                    let consumed = match idx < prefix_len {
+                        true => idx < leading,
+                        false => consume_literals && input.peek() == Some(&literal),
+                    };
+
+                    if consumed {
+                        input.next();
+                    }
+                }
+                TokenKind::Slot(slot) => loop {
+                    match input.peek() {
+                        Some(&ch) if slot.accepts(ch) => {
+                            input.next();
+                            masked.push(ch);
+                            raw.push(ch);
+                            filled_len = masked.len();
+                            break;
+                        }
+                        // an optional slot is skipped instead of dropping the character
+                        Some(_) if token.optional => break,
+                        Some(_) => {
+                            input.next();
+                        }
+                        None => {
+                            complete &= token.optional;
+                            if !token.optional {
+                                break 'tokens;
+                            }
+                            break;
+                        }
+                    }
+                },
+            }
+        }
+
+        masked.truncate(filled_len);
+
+        MaskedValue {
+            masked,
+            raw,
+            complete,
+        }
+    }
+
+    /// Literals before the first slot of the mask.
+    fn literal_prefix(&self) -> impl Iterator<Item = char> + '_ {
+        self.tokens.iter().map_while(|token| match token.kind {
+            TokenKind::Literal(literal) => Some(literal),
+            TokenKind::Slot(_) => None,
+        })
+    }
+
+    /// Formats a value being edited and moves the caret, given as a char index,
+    /// to the same logical position in the masked value.
+    pub fn format_with_caret(&self, value: &str, caret: usize) -> (MaskedValue, usize) {
+        let before_caret: String = value.chars().take(caret).collect();
+        let new_caret = self.format(&before_caret).masked.chars().count();
+
+        (self.format(value), new_caret)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mask::Mask;
+
+    #[test]
+    fn it_formats_a_phone_number() {
+        let mask = Mask::new("(999) 999-9999");
+
+        let value = mask.format("5551234567");
+        assert_eq!(value.masked, "(555) 123-4567");
+        assert_eq!(value.raw, "5551234567");
+        assert!(value.complete);
+    }
+
+    #[test]
+    fn it_formats_a_partial_value_without_trailing_literals() {
+        let mask = Mask::new("(999) 999-9999");
+
+        let value = mask.format("555");
+        assert_eq!(value.masked, "(555");
+        assert!(!value.complete);
+
+        assert_eq!(mask.format("").masked, "");
+    }
+
+    #[test]
+    fn it_reformats_a_masked_value() {
+        let mask = Mask::new("9999 9999 9999 9999");
+
+        assert_eq!(
+            mask.format("4111 1111 1111 1111").masked,
+            "4111 1111 1111 1111"
+        );
+        assert_eq!(mask.format("4111-11x11").masked, "4111 1111");
+    }
+
+    #[test]
+    fn it_drops_characters_not_fitting_the_slot() {
+        let mask = Mask::new("aa99");
+
+        let value = mask.format("1a2b34");
+        assert_eq!(value.masked, "ab34");
+        assert_eq!(value.raw, "ab34");
+    }
+
+    #[test]
+    fn it_formats_a_raw_value_sharing_characters_with_the_literals() {
+        let mask = Mask::new("+1 (999) 999-9999");
+
+        let value = mask.format_raw("1234567890");
+        assert_eq!(value.masked, "+1 (123) 456-7890");
+        assert_eq!(value.raw, "1234567890");
+        assert!(value.complete);
+
+        // the masked value itself keeps its literals
+        assert_eq!(mask.format("+1 (123) 456-7890").raw, "1234567890");
+    }
+
+    #[test]
+    fn it_fills_a_slot_with_a_character_equal_to_a_leading_literal() {
+        let mask = Mask::new("+1 (999) 999-9999");
+
+        // first keystroke into the empty field
+        let value = mask.format("1");
+        assert_eq!(value.masked, "+1 (1");
+        assert_eq!(value.raw, "1");
+
+        let (value, caret) = mask.format_with_caret("1", 1);
+        assert_eq!(value.masked, "+1 (1");
+        assert_eq!(caret, 5);
+
+        // a prefix partly deleted is still taken as the literals
+        assert_eq!(mask.format("+1 ").masked, "");
+        assert_eq!(mask.format("+1 (55").masked, "+1 (55");
+    }
+
+    #[test]
+    fn it_keeps_escaped_literals() {
+        let mask = Mask::new("\\99-9");
+
+        assert_eq!(mask.format("12").masked, "91-2");
+        assert_eq!(mask.format("912").masked, "91-2");
+    }
+
+    #[test]
+    fn it_formats_optional_segments() {
+        let mask = Mask::new("99999[-9999]");
+
+        let short = mask.format("12345");
+        assert_eq!(short.masked, "12345");
+        assert!(short.complete);
+
+        let long = mask.format("123456789");
+        assert_eq!(long.masked, "12345-6789");
+        assert_eq!(long.raw, "123456789");
+    }
+
+    #[test]
+    fn it_formats_an_iban() {
+        let mask = Mask::new("aa99 **** **** **** **** [****]");
+
+        let value = mask.format("de89370400440532013000");
+        assert_eq!(value.masked, "de89 3704 0044 0532 0130 00");
+        assert!(value.complete);
+    }
+
+    #[test]
+    fn it_moves_the_caret_past_inserted_literals() {
+        let mask = Mask::new("99/99/9999");
+
+        // typing the third digit at the end
+        let (value, caret) = mask.format_with_caret("123", 3);
+        assert_eq!(value.masked, "12/3");
+        assert_eq!(caret, 4);
+
+        // typing in the middle of the value
+        let (value, caret) = mask.format_with_caret("12/03/2024", 1);
+        assert_eq!(value.masked, "12/03/2024");
+        assert_eq!(caret, 1);
+
+        // deleting the character right after a literal
+        let (value, caret) = mask.format_with_caret("12/", 3);
+        assert_eq!(value.masked, "12");
+        assert_eq!(caret, 2);
+    }
+}
@@ -0,0 +1,135 @@
+//! Matching of typed text against option labels, for the inputs filtering a local list.
+
+use leptos::{Callable, Callback};
+
+use crate::highlight::fold_char;
+
+/// How the typed text is matched against the labels, regardless of case and accents.
+#[derive(Clone, Copy, Default)]
+pub enum Matcher {
+    /// labels starting with the text
+    Prefix,
+    /// labels containing the text, the earlier the better
+    #[default]
+    Substring,
+    /// labels containing the characters of the text in order, e.g. `usa` for
+    /// `United States of America`, ranked by consecutive characters and word starts
+    Fuzzy,
+    /// scores a label against the text, `None` leaving it out and higher scores first
+    Custom(Callback<(String, String), Option<i32>>),
+}
+
+impl Matcher {
+    /// Score of the label for the text, `None` when it does not match.
+    pub fn score(&self, label: &str, query: &str) -> Option<i32> {
+        if let Matcher::Custom(score) = self {
+            return score.call((label.to_string(), query.to_string()));
+        }
+
+        let label: Vec<char> = label.chars().map(fold_char).collect();
+        let query: Vec<char> = query.trim().chars().map(fold_char).collect();
+
+        if query.is_empty() {
+            return Some(0);
+        }
+
+        match self {
+            Matcher::Prefix => label.starts_with(&query).then_some(0),
+            Matcher::Substring => label
+                .windows(query.len())
+                .position(|window| window == query)
+                .map(|position| -(position as i32)),
+            Matcher::Fuzzy => fuzzy_score(&label, &query),
+            Matcher::Custom(_) => None,
+        }
+    }
+}
+
+fn fuzzy_score(label: &[char], query: &[char]) -> Option<i32> {
+    let mut score = 0;
+    let mut from = 0;
+    let mut previous: Option<usize> = None;
+
+    for ch in query {
+        let position = (from..label.len()).find(|idx| label[*idx] == *ch)?;
+        let skipped = position - previous.map_or(0, |previous| previous + 1);
+
+        score += match previous {
+            Some(previous) if previous + 1 == position => 5,
+            _ => 1,
+        };
+
+        if position == 0 || !label[position - 1].is_alphanumeric() {
+            score += 3;
+        }
+
+        score -= skipped.min(3) as i32;
+        previous = Some(position);
+        from = position + 1;
+    }
+
+    Some(score)
+}
+
+/// Options whose label matches the text, best first, in their order otherwise.
+pub fn filter_options<T, L>(options: &[T], label: L, query: &str, matcher: Matcher) -> Vec<T>
+where
+    T: Clone,
+    L: Fn(&T) -> String,
+{
+    let mut scored: Vec<(i32, &T)> = options
+        .iter()
+        .filter_map(|option| {
+            matcher
+                .score(&label(option), query)
+                .map(|score| (score, option))
+        })
+        .collect();
+
+    // stable, keeps the given order among equal scores
+    scored.sort_by(|(score, _), (other, _)| other.cmp(score));
+
+    scored
+        .into_iter()
+        .map(|(_, option)| option.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matcher::{filter_options, Matcher};
+
+    const COUNTRIES: [&str; 5] = [
+        "Austria",
+        "Australia",
+        "Mauritius",
+        "Côte d'Ivoire",
+        "Chile",
+    ];
+
+    fn filter(query: &str, matcher: Matcher) -> Vec<&'static str> {
+        filter_options(&COUNTRIES, |country| country.to_string(), query, matcher)
+    }
+
+    #[test]
+    fn it_filters_by_prefix() {
+        assert_eq!(filter("aus", Matcher::Prefix), vec!["Austria", "Australia"]);
+        assert_eq!(filter("", Matcher::Prefix).len(), COUNTRIES.len());
+    }
+
+    #[test]
+    fn it_ranks_the_earlier_substrings_first() {
+        assert_eq!(
+            filter("AU", Matcher::Substring),
+            vec!["Austria", "Australia", "Mauritius"]
+        );
+        assert_eq!(filter("cote", Matcher::Substring), vec!["Côte d'Ivoire"]);
+    }
+
+    #[test]
+    fn it_ranks_the_fuzzy_matches() {
+        assert_eq!(filter("cdi", Matcher::Fuzzy), vec!["Côte d'Ivoire"]);
+        // closer characters rank higher
+        assert_eq!(filter("asa", Matcher::Fuzzy), vec!["Australia", "Austria"]);
+    }
+}
@@ -0,0 +1,594 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_effect, create_memo, create_node_ref, create_rw_signal, document,
+    ev::{FocusEvent, KeyboardEvent, MouseEvent},
+    html::Div,
+    view, Callable, Callback, CollectView, IntoView, MaybeSignal, Show, SignalGet,
+    SignalGetUntracked, SignalSet, SignalWith, SignalWithUntracked,
+};
+use leptos_use::on_click_outside;
+
+use crate::{
+    chip::Chip,
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    icons::{check::Check, chevron_up_down::ChevronUpDown},
+    id::resolve_id,
+    listbox::{first_enabled, last_enabled, move_active, PAGE_SIZE},
+    select::{group_runs, SelectOption},
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-multi-select rounded-md border box-border bg-white mb-2.5 focus-within:border";
+
+/// Adds the value when missing and there is room left, removes it otherwise.
+pub fn toggle_selected<T>(selected: &[T], value: &T, max_selected: Option<usize>) -> Vec<T>
+where
+    T: Clone + PartialEq,
+{
+    match selected.iter().position(|item| item == value) {
+        Some(idx) => {
+            let mut selected = selected.to_vec();
+            selected.remove(idx);
+            selected
+        }
+        None if max_selected.is_some_and(|max| selected.len() >= max) => selected.to_vec(),
+        None => {
+            let mut selected = selected.to_vec();
+            selected.push(value.clone());
+            selected
+        }
+    }
+}
+
+/// Toggles the value of an enabled option, a disabled one keeping its state.
+pub fn toggle_enabled<T>(
+    options: &[SelectOption<T>],
+    selected: &[T],
+    value: &T,
+    max_selected: Option<usize>,
+) -> Vec<T>
+where
+    T: Clone + PartialEq,
+{
+    match is_disabled(options, value) {
+        true => selected.to_vec(),
+        false => toggle_selected(selected, value, max_selected),
+    }
+}
+
+fn is_disabled<T>(options: &[SelectOption<T>], value: &T) -> bool
+where
+    T: PartialEq,
+{
+    options
+        .iter()
+        .any(|option| option.disabled && option.value == *value)
+}
+
+/// Selects every enabled option up to the limit, keeping the current selection first.
+pub fn select_all<T>(
+    options: &[SelectOption<T>],
+    selected: &[T],
+    max_selected: Option<usize>,
+) -> Vec<T>
+where
+    T: Clone + PartialEq,
+{
+    let mut all = selected.to_vec();
+
+    for option in options.iter().filter(|option| !option.disabled) {
+        if max_selected.is_some_and(|max| all.len() >= max) {
+            break;
+        }
+
+        if !all.contains(&option.value) {
+            all.push(option.value.clone());
+        }
+    }
+
+    all
+}
+
+/// Removes the values of the enabled options, the disabled ones staying selected.
+pub fn deselect_all<T>(options: &[SelectOption<T>], selected: &[T]) -> Vec<T>
+where
+    T: Clone + PartialEq,
+{
+    selected
+        .iter()
+        .filter(|value| {
+            !options
+                .iter()
+                .any(|option| !option.disabled && option.value == **value)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Nothing is left to select, every enabled option being selected or the limit reached.
+pub fn is_all_selected<T>(
+    options: &[SelectOption<T>],
+    selected: &[T],
+    max_selected: Option<usize>,
+) -> bool
+where
+    T: PartialEq,
+{
+    max_selected.is_some_and(|max| selected.len() >= max)
+        || options
+            .iter()
+            .filter(|option| !option.disabled)
+            .all(|option| selected.contains(&option.value))
+}
+
+/// Several choices among a fixed list of options, shown as removable chips.
+#[component]
+pub fn MultiSelect<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "")] placeholder: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    /// no more options can be picked once reached
+    #[prop(optional)]
+    max_selected: Option<usize>,
+    /// adds a "select all" entry at the top of the list
+    #[prop(optional)]
+    with_select_all: bool,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    #[prop(into)] options: MaybeSignal<Vec<SelectOption<T>>>,
+    control: Arc<FormControl<Vec<T>>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let form_id = resolve_id(id, "multi-select");
+    let label_id = format!("{form_id}-label");
+    let listbox_id = format!("{form_id}-listbox");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let multi_select_ref = create_node_ref::<Div>();
+    let control_value = control.value;
+
+    let options = create_memo(move |_| options.get());
+    let open = create_rw_signal(false);
+    let active = create_rw_signal(None::<usize>);
+
+    let option_id = {
+        let form_id = form_id.clone();
+
+        move |idx: usize| format!("{form_id}-option-{idx}")
+    };
+
+    let selected = move || control_value.get().unwrap_or_default();
+
+    let is_selected = move |idx: usize| {
+        control_value.with(|value| {
+            options.with(|options| {
+                value
+                    .as_ref()
+                    .zip(options.get(idx))
+                    .is_some_and(|(value, option)| value.contains(&option.value))
+            })
+        })
+    };
+
+    let limit_reached = move || {
+        control_value
+            .with(|value| max_selected.is_some_and(|max| value.as_ref().map_or(0, Vec::len) >= max))
+    };
+
+    // options that cannot be toggled, either disabled or past the limit
+    let is_locked = move |idx: usize| {
+        let disabled =
+            options.with(|options| options.get(idx).is_none_or(|option| option.disabled));
+
+        disabled || (limit_reached() && !is_selected(idx))
+    };
+
+    let locked_flags = move || {
+        let len = options.with_untracked(Vec::len);
+
+        (0..len).map(is_locked).collect::<Vec<_>>()
+    };
+
+    let set_selected = {
+        let control_ref = Arc::clone(&control);
+
+        move |selected: Vec<T>| {
+            control_ref.mark_as_dirty();
+            control_ref.set_value((!selected.is_empty()).then_some(selected));
+        }
+    };
+
+    let toggle = {
+        let set_selected = set_selected.clone();
+
+        move |idx: usize| {
+            let toggled = options.with_untracked(|options| {
+                options
+                    .get(idx)
+                    .map(|option| toggle_enabled(options, &selected(), &option.value, max_selected))
+            });
+
+            if let Some(toggled) = toggled {
+                set_selected(toggled);
+            }
+        }
+    };
+
+    let all_selected = move || {
+        options.with(|options| {
+            control_value.with(|value| {
+                is_all_selected(options, value.as_deref().unwrap_or_default(), max_selected)
+            })
+        })
+    };
+
+    let toggle_all = {
+        let set_selected = set_selected.clone();
+
+        move || {
+            let selected = selected();
+
+            set_selected(options.with_untracked(|options| match all_selected() {
+                true => deselect_all(options, &selected),
+                false => select_all(options, &selected, max_selected),
+            }))
+        }
+    };
+
+    let remove = {
+        let set_selected = set_selected.clone();
+
+        move |value: T| {
+            let removed = options
+                .with_untracked(|options| toggle_enabled(options, &selected(), &value, None));
+
+            set_selected(removed);
+        }
+    };
+
+    let close = move || {
+        open.set(false);
+        active.set(None);
+    };
+
+    let open_list = move || {
+        active.set(first_enabled(&locked_flags()));
+        open.set(true);
+    };
+
+    // handler to detect clicks outside the element and close the open listbox.
+    let _ = on_click_outside(multi_select_ref, move |_| {
+        if open.get_untracked() {
+            close();
+        }
+    });
+
+    let on_keydown = {
+        let toggle = toggle.clone();
+        let toggle_all = toggle_all.clone();
+        let remove = remove.clone();
+
+        move |ev: KeyboardEvent| {
+            let key = ev.key();
+            let locked = locked_flags();
+
+            if !open.get_untracked() {
+                match key.as_str() {
+                    "ArrowDown" | "ArrowUp" | "Enter" | " " => open_list(),
+                    // remove the last chip
+                    "Backspace" => match selected().last() {
+                        Some(last) => remove(last.clone()),
+                        None => return,
+                    },
+                    _ => return,
+                }
+
+                ev.prevent_default();
+                return;
+            }
+
+            let current = active.get_untracked();
+
+            match key.as_str() {
+                "ArrowDown" => active.set(move_active(&locked, current, 1)),
+                "ArrowUp" => active.set(move_active(&locked, current, -1)),
+                "PageDown" => active.set(move_active(&locked, current, PAGE_SIZE as isize)),
+                "PageUp" => active.set(move_active(&locked, current, -(PAGE_SIZE as isize))),
+                "Home" => active.set(first_enabled(&locked)),
+                "End" => active.set(last_enabled(&locked)),
+                "a" if ev.ctrl_key() || ev.meta_key() => toggle_all(),
+                "Enter" | " " => {
+                    if let Some(idx) = current {
+                        toggle(idx);
+                    }
+                }
+                "Escape" => close(),
+                "Tab" => {
+                    close();
+                    return;
+                }
+                _ => return,
+            }
+
+            ev.prevent_default();
+        }
+    };
+
+    let on_select_all = Callback::new(move |_ev: MouseEvent| toggle_all());
+
+    let on_trigger_click = move |_ev: MouseEvent| match open.get_untracked() {
+        true => close(),
+        false => open_list(),
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // keep the active option visible while navigating
+    create_effect({
+        let option_id = option_id.clone();
+
+        move |_| {
+            if let Some(idx) = active.get() {
+                if let Some(element) = document().get_element_by_id(&option_id(idx)) {
+                    element.scroll_into_view_with_bool(false);
+                }
+            }
+        }
+    });
+
+    // multi select specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the combobox while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let active_descendant = {
+        let option_id = option_id.clone();
+
+        move || active.get().filter(|_| open.get()).map(&option_id)
+    };
+
+    let render_option = {
+        let option_id = option_id.clone();
+
+        move |idx: usize, option: SelectOption<T>| {
+            let toggle = toggle.clone();
+            let is_active = move || active.get() == Some(idx);
+
+            view! {
+                <li id=option_id(idx)
+                    role="option"
+                    aria-selected=move || is_selected(idx).to_string()
+                    aria-disabled=move || is_locked(idx).then_some("true")
+                    class="multi-select-option flex flex-row items-center justify-between text-sm text-slate-900 py-2 px-2"
+                    class=("bg-slate-200", is_active)
+                    class=("cursor-pointer", move || !is_locked(idx))
+                    class=("opacity-50", move || is_locked(idx) && !is_selected(idx))
+                    on:mousedown=|ev: MouseEvent| ev.prevent_default()
+                    on:mousemove=move |_ev: MouseEvent| {
+                        if !is_active() {
+                            active.set(Some(idx));
+                        }
+                    }
+                    on:click=move |_ev: MouseEvent| toggle(idx)>
+                    <span>{option.label}</span>
+                    <Show when=move || is_selected(idx)>
+                        <Check class="w-4 h-4 text-slate-900" />
+                    </Show>
+                </li>
+            }
+        }
+    };
+
+    let render_options = {
+        let form_id = form_id.clone();
+
+        move || {
+            options.with(|options| {
+                group_runs(options)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(run, (group, indexes))| {
+                        let items = indexes
+                            .into_iter()
+                            .map(|idx| render_option(idx, options[idx].clone()))
+                            .collect_view();
+
+                        match group {
+                            Some(group) => {
+                                // runs are numbered, a name can repeat or differ only by case
+                                let group_id = format!("{form_id}-group-{run}");
+
+                                view! {
+                                    <li role="presentation">
+                                        <div id=group_id.clone() class="multi-select-group px-2 pt-2 pb-1 text-xs font-semibold text-slate-500">
+                                            {group}
+                                        </div>
+                                        <ul role="group" aria-labelledby=group_id>
+                                            {items}
+                                        </ul>
+                                    </li>
+                                }
+                                .into_view()
+                            }
+                            None => items,
+                        }
+                    })
+                    .collect_view()
+            })
+        }
+    };
+
+    let render_chips = move || {
+        options.with(|options| {
+            selected()
+                .into_iter()
+                .map(|value| {
+                    let label = options
+                        .iter()
+                        .find(|option| option.value == value)
+                        .map(|option| option.label.clone())
+                        .unwrap_or_default();
+                    let remove = remove.clone();
+
+                    // a disabled option cannot be removed
+                    match is_disabled(options, &value) {
+                        true => view! { <Chip label=label /> },
+                        false => view! {
+                            <Chip label=label on_remove=move |_| remove(value.clone()) />
+                        },
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div node_ref=multi_select_ref class=class_props_by_state>
+            <div class="lumx-multi-select-relative relative">
+                <div class="multi-select-control flex flex-col p-2.5">
+                    <label id=label_id.clone() for=form_id.clone() class="multi-select-label text-xs text-slate-900">
+                        {label}
+                    </label>
+
+                    <div class="flex flex-row justify-between items-center gap-x-1">
+                        <div class="multi-select-chips flex flex-row flex-wrap gap-1 min-h-5">
+                            {render_chips}
+                        </div>
+
+                        <button
+                            type="button"
+                            id=form_id
+                            role="combobox"
+                            aria-haspopup="listbox"
+                            aria-controls=listbox_id.clone()
+                            aria-labelledby=label_id.clone()
+                            aria-expanded=move || open.get().to_string()
+                            aria-activedescendant=active_descendant
+                            aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                            aria-describedby=described_by
+                            on:click=on_trigger_click
+                            on:keydown=on_keydown
+                            on:blur=on_blur
+                            class="multi-select-trigger grow flex flex-row justify-end items-center gap-x-1 outline-none min-h-5">
+                            <Show when=move || control_value.with(Option::is_none)>
+                                <span class="multi-select-placeholder grow text-left text-sm text-slate-400">{placeholder}</span>
+                            </Show>
+                            <ChevronUpDown class="w-4 h-4 text-slate-500" />
+                        </button>
+                    </div>
+                </div>
+
+                <Show when=move || open.get()>
+                    <div class="multi-select-overlay-panel w-full absolute mt-1 z-10 rounded-md border box-border border-slate-200 bg-white py-1">
+                        <Show when=move || with_select_all>
+                            <button type="button"
+                                tabindex="-1"
+                                on:mousedown=|ev: MouseEvent| ev.prevent_default()
+                                on:click=move |ev: MouseEvent| on_select_all.call(ev)
+                                class="multi-select-all w-full text-left text-sm font-semibold text-slate-900 py-2 px-2 hover:bg-slate-200 cursor-pointer">
+                                {move || if all_selected() { "Deselect all" } else { "Select all" }}
+                            </button>
+                        </Show>
+
+                        <ul id=listbox_id.clone()
+                            role="listbox"
+                            aria-multiselectable="true"
+                            aria-labelledby=label_id.clone()
+                            tabindex="-1"
+                            class="max-h-60 overflow-y-auto">
+                            {render_options.clone()}
+                        </ul>
+                    </div>
+                </Show>
+            </div>
+
+            <div class="px-2.5 pb-1">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        multi_select::{
+            deselect_all, is_all_selected, select_all, toggle_enabled, toggle_selected,
+        },
+        select::SelectOption,
+    };
+
+    #[test]
+    fn it_toggles_within_the_limit() {
+        assert_eq!(toggle_selected(&[1, 2], &3, None), vec![1, 2, 3]);
+        assert_eq!(toggle_selected(&[1, 2], &1, None), vec![2]);
+        assert_eq!(toggle_selected(&[1, 2], &3, Some(2)), vec![1, 2]);
+        assert_eq!(toggle_selected(&[1, 2], &2, Some(2)), vec![1]);
+    }
+
+    #[test]
+    fn it_keeps_the_state_of_the_disabled_options() {
+        let options = vec![
+            SelectOption::new(1, "admin"),
+            SelectOption::new(2, "owner").with_disabled(true),
+        ];
+
+        assert_eq!(toggle_enabled(&options, &[2], &2, None), vec![2]);
+        assert!(toggle_enabled(&options, &[], &2, None).is_empty());
+        assert_eq!(toggle_enabled(&options, &[2], &1, None), vec![2, 1]);
+        assert_eq!(toggle_enabled(&options, &[2, 1], &1, None), vec![2]);
+    }
+
+    #[test]
+    fn it_selects_all_enabled_options_within_the_limit() {
+        let options = vec![
+            SelectOption::new(1, "admin"),
+            SelectOption::new(2, "owner").with_disabled(true),
+            SelectOption::new(3, "editor"),
+            SelectOption::new(4, "viewer"),
+        ];
+
+        assert_eq!(select_all(&options, &[], None), vec![1, 3, 4]);
+        assert_eq!(select_all(&options, &[4], Some(2)), vec![4, 1]);
+    }
+
+    #[test]
+    fn it_deselects_all_but_the_disabled_options() {
+        let options = vec![
+            SelectOption::new(1, "admin"),
+            SelectOption::new(2, "owner").with_disabled(true),
+            SelectOption::new(3, "editor"),
+        ];
+
+        assert_eq!(deselect_all(&options, &[2, 1, 3]), vec![2]);
+        assert!(is_all_selected(&options, &[1, 3], None));
+        assert!(!is_all_selected(&options, &[2, 1], None));
+        // the limit ends the selection too, so it can be undone
+        assert!(is_all_selected(&options, &[3], Some(1)));
+    }
+}
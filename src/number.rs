@@ -0,0 +1,301 @@
+//! Locale aware formatting, parsing and stepping of numeric values.
+
+/// Numeric types a [`NumberInput`](crate::number_input::NumberInput) can be bound to.
+pub trait NumberValue: Copy + PartialOrd + 'static {
+    /// whether fractional values are rounded away
+    const INTEGER: bool;
+
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+impl NumberValue for i32 {
+    const INTEGER: bool = true;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as i32
+    }
+}
+
+impl NumberValue for i64 {
+    const INTEGER: bool = true;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as i64
+    }
+}
+
+impl NumberValue for f64 {
+    const INTEGER: bool = false;
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// Separators used to display numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    pub thousands: Option<char>,
+    pub decimal: char,
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self::en()
+    }
+}
+
+impl NumberLocale {
+    pub fn new(thousands: Option<char>, decimal: char) -> Self {
+        Self { thousands, decimal }
+    }
+
+    /// `1,234.5`
+    pub fn en() -> Self {
+        Self::new(Some(','), '.')
+    }
+
+    /// `1.234,5`
+    pub fn de() -> Self {
+        Self::new(Some('.'), ',')
+    }
+
+    /// `1 234,5`, grouped with a narrow no-break space
+    pub fn fr() -> Self {
+        Self::new(Some('\u{202f}'), ',')
+    }
+
+    /// Formats a value with the given number of decimals, or as few as needed when `None`.
+    pub fn format(&self, value: f64, precision: Option<usize>) -> String {
+        let plain = match precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => value.to_string(),
+        };
+
+        let (sign, plain) = match plain.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", plain.as_str()),
+        };
+
+        let (integer, fraction) = match plain.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (plain, None),
+        };
+
+        let mut formatted = String::from(sign);
+
+        for (idx, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - idx;
+
+            if idx > 0 && remaining % 3 == 0 {
+                if let Some(thousands) = self.thousands {
+                    formatted.push(thousands);
+                }
+            }
+
+            formatted.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal);
+            formatted.push_str(fraction);
+        }
+
+        formatted
+    }
+
+    /// Parses a value typed by the user, ignoring the thousands separators.
+    pub fn parse(&self, value: &str) -> Option<f64> {
+        let normalized: String = value
+            .trim()
+            .chars()
+            .filter(|ch| Some(*ch) != self.thousands && !ch.is_whitespace())
+            .map(|ch| if ch == self.decimal { '.' } else { ch })
+            .collect();
+
+        if normalized.is_empty() {
+            return None;
+        }
+
+        normalized
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+}
+
+/// Rounds a value to the given number of decimals.
+pub fn round_to(value: f64, precision: Option<usize>) -> f64 {
+    match precision {
+        Some(precision) => {
+            let factor = 10f64.powi(precision as i32);
+            (value * factor).round() / factor
+        }
+        None => value,
+    }
+}
+
+/// Keeps a value within the optional bounds.
+pub fn clamp(value: f64, min: Option<f64>, max: Option<f64>) -> f64 {
+    let value = min.map_or(value, |min| value.max(min));
+
+    max.map_or(value, |max| value.min(max))
+}
+
+/// Moves a value by a number of steps, starting from `min` or zero when there is no value.
+///
+/// Without a `precision` the decimals of the step and of the value are kept.
+pub fn step_value(
+    value: Option<f64>,
+    step: f64,
+    steps: i32,
+    min: Option<f64>,
+    max: Option<f64>,
+    precision: Option<usize>,
+) -> f64 {
+    let base = value.or(min).unwrap_or_default();
+
+    // the steps are added as floats, drop the noise like `0.30000000000000004`
+    let precision = precision.unwrap_or_else(|| step_precision(step).max(step_precision(base)));
+    let stepped = round_to(base + step * steps as f64, Some(precision));
+
+    clamp(stepped, min, max)
+}
+
+/// Number of decimals of a step, e.g. `2` for `0.25`.
+pub fn step_precision(step: f64) -> usize {
+    step.to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+/// Snaps a value to the closest step counted from `min`, keeping it within the bounds.
+pub fn snap_to_step(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return clamp(value, Some(min), Some(max));
+    }
+
+    let snapped = min + ((value - min) / step).round() * step;
+
+    // the steps are added as floats, drop the noise like `0.30000000000000004`
+    clamp(
+        round_to(snapped, Some(step_precision(step).max(step_precision(min)))),
+        Some(min),
+        Some(max),
+    )
+}
+
+/// Position of a value between the bounds, from `0.0` to `1.0`.
+pub fn value_ratio(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+
+    clamp((value - min) / (max - min), Some(0.0), Some(1.0))
+}
+
+/// Value at a position between the bounds, snapped to the steps.
+pub fn ratio_value(ratio: f64, min: f64, max: f64, step: f64) -> f64 {
+    let ratio = clamp(ratio, Some(0.0), Some(1.0));
+
+    snap_to_step(min + ratio * (max - min), min, max, step)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::number::{
+        clamp, ratio_value, round_to, snap_to_step, step_precision, step_value, value_ratio,
+        NumberLocale,
+    };
+
+    #[test]
+    fn it_formats_with_the_locale_separators() {
+        assert_eq!(
+            NumberLocale::en().format(1234567.891, Some(2)),
+            "1,234,567.89"
+        );
+        assert_eq!(
+            NumberLocale::de().format(1234567.891, Some(2)),
+            "1.234.567,89"
+        );
+        assert_eq!(
+            NumberLocale::fr().format(-1234.5, Some(2)),
+            "-1\u{202f}234,50"
+        );
+        assert_eq!(NumberLocale::en().format(999.0, None), "999");
+        assert_eq!(NumberLocale::en().format(-123456.0, Some(0)), "-123,456");
+    }
+
+    #[test]
+    fn it_parses_with_the_locale_separators() {
+        assert_eq!(NumberLocale::en().parse("1,234.5"), Some(1234.5));
+        assert_eq!(NumberLocale::de().parse("1.234,5"), Some(1234.5));
+        assert_eq!(NumberLocale::fr().parse("1 234,5"), Some(1234.5));
+        assert_eq!(NumberLocale::en().parse(" -42 "), Some(-42.0));
+        assert_eq!(NumberLocale::en().parse(""), None);
+        assert_eq!(NumberLocale::en().parse("12a"), None);
+    }
+
+    #[test]
+    fn it_rounds_and_clamps() {
+        assert_eq!(round_to(0.1 + 0.2, Some(2)), 0.3);
+        assert_eq!(round_to(1.005, None), 1.005);
+        assert_eq!(clamp(12.0, Some(0.0), Some(10.0)), 10.0);
+        assert_eq!(clamp(-1.0, Some(0.0), None), 0.0);
+    }
+
+    #[test]
+    fn it_steps_within_the_bounds() {
+        assert_eq!(step_value(Some(1.0), 0.1, 2, None, None, Some(2)), 1.2);
+        assert_eq!(step_value(None, 1.0, 1, Some(5.0), None, None), 6.0);
+        assert_eq!(step_value(Some(9.5), 1.0, 1, None, Some(10.0), None), 10.0);
+        assert_eq!(step_value(Some(0.0), 5.0, -1, Some(0.0), None, None), 0.0);
+    }
+
+    #[test]
+    fn it_steps_without_float_noise() {
+        let stepped = (0..3).fold(None, |value, _| {
+            Some(step_value(value, 0.1, 1, None, None, None))
+        });
+
+        assert_eq!(stepped, Some(0.3));
+        assert_eq!(step_value(Some(2.35), 0.1, 1, None, None, None), 2.45);
+        assert_eq!(step_value(Some(1.0), 0.25, -1, None, None, None), 0.75);
+    }
+
+    #[test]
+    fn it_snaps_to_the_steps() {
+        assert_eq!(step_precision(1.0), 0);
+        assert_eq!(step_precision(0.25), 2);
+        assert_eq!(snap_to_step(12.4, 0.0, 100.0, 5.0), 10.0);
+        assert_eq!(snap_to_step(12.5, 0.0, 100.0, 5.0), 15.0);
+        assert_eq!(snap_to_step(0.29, 0.0, 1.0, 0.1), 0.3);
+        assert_eq!(snap_to_step(6.9, 1.0, 10.0, 4.0), 5.0);
+        assert_eq!(snap_to_step(7.0, 1.0, 10.0, 4.0), 9.0);
+        assert_eq!(snap_to_step(120.0, 0.0, 100.0, 5.0), 100.0);
+        assert_eq!(snap_to_step(-3.0, 0.0, 100.0, 5.0), 0.0);
+    }
+
+    #[test]
+    fn it_converts_between_values_and_ratios() {
+        assert_eq!(value_ratio(25.0, 0.0, 100.0), 0.25);
+        assert_eq!(value_ratio(150.0, 0.0, 100.0), 1.0);
+        assert_eq!(value_ratio(5.0, 10.0, 10.0), 0.0);
+        assert_eq!(ratio_value(0.26, 0.0, 100.0, 5.0), 25.0);
+        assert_eq!(ratio_value(-0.5, 0.0, 100.0, 5.0), 0.0);
+        assert_eq!(ratio_value(0.5, -1.0, 1.0, 0.5), 0.0);
+    }
+}
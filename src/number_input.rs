@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_effect, create_node_ref,
+    ev::{KeyboardEvent, MouseEvent},
+    event_target_value,
+    html::Input,
+    use_context, view, Callback, IntoView, SignalGet, SignalGetUntracked, SignalWith,
+};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+        validators::min::MIN_NAME,
+    },
+    icons::{minus::Minus, plus::Plus},
+    id::resolve_id,
+    number::{clamp, round_to, step_value, NumberLocale, NumberValue},
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-number-input flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+const STEPPER_CLASS: &str =
+    "lumx-number-input-stepper shrink-0 cursor-pointer rounded-full p-0.5 text-slate-900 hover:bg-slate-200 disabled:opacity-40 disabled:cursor-not-allowed";
+
+/// steps moved by `PageUp` and `PageDown`
+const PAGE_STEPS: i32 = 10;
+
+/// Numeric field bound to an integer or decimal control.
+///
+/// Bounds are applied when stepping and when the field loses focus, values typed
+/// out of bounds are kept so validators like `Min` can report them. Without a `min`,
+/// the bound of a `Min` validator of the control is used.
+#[component]
+pub fn NumberInput<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "")] placeholder: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    /// defaults to the bound of a `Min` validator
+    #[prop(optional)]
+    min: Option<f64>,
+    #[prop(optional)] max: Option<f64>,
+    #[prop(default = 1.0)] step: f64,
+    /// number of decimals shown, integers never show decimals
+    #[prop(optional)]
+    precision: Option<usize>,
+    /// separators, defaults to a provided `NumberLocale` or to `NumberLocale::en`
+    #[prop(optional)]
+    locale: Option<NumberLocale>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<T>>,
+) -> impl IntoView
+where
+    T: NumberValue,
+{
+    let form_id = resolve_id(id, "number-input");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let input_ref = create_node_ref::<Input>();
+    let control_value = control.value;
+    let min = min.or_else(|| {
+        control
+            .constraint(MIN_NAME)
+            .and_then(|meta| meta.get_number("min_val"))
+    });
+
+    let locale = locale
+        .or_else(use_context::<NumberLocale>)
+        .unwrap_or_default();
+    let precision = match T::INTEGER {
+        true => Some(0),
+        false => precision,
+    };
+
+    let display_value = move |value: Option<T>| {
+        value
+            .map(|value| locale.format(value.to_f64(), precision))
+            .unwrap_or_default()
+    };
+
+    // move the value by a number of steps, notifying the control
+    let step_by = {
+        let control_ref = Arc::clone(&control);
+
+        move |steps: i32| {
+            let value = control_value.get_untracked().map(T::to_f64);
+            let stepped = step_value(value, step, steps, min, max, precision);
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value(Some(T::from_f64(stepped)));
+        }
+    };
+
+    // notify the parsed value to the control while the user types
+    let on_input = {
+        let control_ref = Arc::clone(&control);
+
+        move |ev| {
+            let input_value = event_target_value(&ev);
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value(locale.parse(&input_value).map(T::from_f64));
+        }
+    };
+
+    // apply bounds and precision, then show the formatted value
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+
+            let value = control_value.get_untracked();
+            let adjusted = value
+                .map(|value| T::from_f64(clamp(round_to(value.to_f64(), precision), min, max)));
+
+            if adjusted != value {
+                control_ref.set_value(adjusted);
+            }
+
+            if let Some(input) = input_ref.get_untracked() {
+                input.set_value(&display_value(adjusted));
+            }
+        }
+    };
+
+    // spinbutton keyboard interaction
+    let on_keydown = {
+        let step_by = step_by.clone();
+        let control_ref = Arc::clone(&control);
+
+        move |ev: KeyboardEvent| {
+            let set_bound = |bound: f64| {
+                control_ref.mark_as_dirty();
+                control_ref.set_value(Some(T::from_f64(bound)));
+            };
+
+            match (ev.key().as_str(), min, max) {
+                ("ArrowUp", _, _) => step_by(1),
+                ("ArrowDown", _, _) => step_by(-1),
+                ("PageUp", _, _) => step_by(PAGE_STEPS),
+                ("PageDown", _, _) => step_by(-PAGE_STEPS),
+                ("Home", Some(min), _) => set_bound(min),
+                ("End", _, Some(max)) => set_bound(max),
+                _ => return,
+            }
+
+            ev.prevent_default();
+        }
+    };
+
+    let on_decrement = {
+        let step_by = step_by.clone();
+
+        move |_ev: MouseEvent| step_by(-1)
+    };
+
+    let on_increment = move |_ev: MouseEvent| step_by(1);
+
+    let at_min = move || {
+        control_value.with(|value| match (value, min) {
+            (Some(value), Some(min)) => value.to_f64() <= min,
+            _ => false,
+        })
+    };
+
+    let at_max = move || {
+        control_value.with(|value| match (value, max) {
+            (Some(value), Some(max)) => value.to_f64() >= max,
+            _ => false,
+        })
+    };
+
+    // reflect control changes into the element, only writing when the typed
+    // text does not already stand for the value so the caret is not moved.
+    create_effect(move |_| {
+        let value = control_value.get();
+
+        if let Some(input) = input_ref.get() {
+            let typed_value = locale.parse(&input.value()).map(T::from_f64);
+
+            if typed_value != value {
+                input.set_value(&display_value(value));
+            }
+        }
+    });
+
+    // field specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the input while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    view! {
+        <div class=class_props_by_state>
+            <label for=form_id.clone() class="text-xs text-slate-900">{label}</label>
+            <div class="lumx-number-input-field flex flex-row items-center gap-x-1">
+                <button type="button"
+                    tabindex="-1"
+                    aria-label="decrement"
+                    disabled=at_min
+                    on:click=on_decrement
+                    class=STEPPER_CLASS>
+                    <Minus class="w-4 h-4" />
+                </button>
+
+                <input
+                    node_ref=input_ref
+                    class="grow min-w-0 outline-none text-sm text-right bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
+                    type="text"
+                    role="spinbutton"
+                    inputmode=if T::INTEGER { "numeric" } else { "decimal" }
+                    autocomplete="off"
+                    aria-valuenow=move || control_value.get().map(T::to_f64)
+                    aria-valuemin=min
+                    aria-valuemax=max
+                    placeholder=placeholder
+                    id=form_id
+                    aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                    aria-describedby=described_by
+                    on:input=on_input
+                    on:blur=on_blur
+                    on:keydown=on_keydown
+                    value=display_value(control_value.get_untracked()) />
+
+                <button type="button"
+                    tabindex="-1"
+                    aria-label="increment"
+                    disabled=at_max
+                    on:click=on_increment
+                    class=STEPPER_CLASS>
+                    <Plus class="w-4 h-4" />
+                </button>
+            </div>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </div>
+    }
+}
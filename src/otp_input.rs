@@ -0,0 +1,413 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_effect, create_rw_signal, document,
+    ev::{Event, FocusEvent, KeyboardEvent},
+    event_target, view, Callable, Callback, CollectView, IntoView, RwSignal, SignalGet,
+    SignalGetUntracked, SignalSet, SignalWith, SignalWithUntracked,
+};
+use web_sys::{wasm_bindgen::JsCast, ClipboardEvent, HtmlInputElement};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    id::resolve_id,
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-otp-input flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+const CELL_STYLE_CLASS: &str =
+    "lumx-otp-cell w-9 h-11 rounded-md border text-center text-lg text-slate-900 bg-white outline-none focus:border-blue-600";
+
+/// Characters a code is made of.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OtpMode {
+    /// digits only
+    #[default]
+    Numeric,
+    /// ascii letters and digits
+    Alphanumeric,
+}
+
+impl OtpMode {
+    pub fn accepts(&self, ch: char) -> bool {
+        match self {
+            OtpMode::Numeric => ch.is_ascii_digit(),
+            OtpMode::Alphanumeric => ch.is_ascii_alphanumeric(),
+        }
+    }
+
+    /// virtual keyboard hint for mobile devices
+    pub fn input_mode(&self) -> &'static str {
+        match self {
+            OtpMode::Numeric => "numeric",
+            OtpMode::Alphanumeric => "text",
+        }
+    }
+
+    /// name of a cell read by screen readers
+    fn cell_name(&self) -> &'static str {
+        match self {
+            OtpMode::Numeric => "digit",
+            OtpMode::Alphanumeric => "character",
+        }
+    }
+}
+
+/// Cells showing a code, the characters beyond `length` are dropped.
+pub fn code_cells(code: &str, length: usize) -> Vec<Option<char>> {
+    let mut cells: Vec<Option<char>> = code.chars().take(length).map(Some).collect();
+    cells.resize(length, None);
+
+    cells
+}
+
+/// Code of the filled cells, in order.
+pub fn cells_code(cells: &[Option<char>]) -> String {
+    cells.iter().flatten().collect()
+}
+
+/// Writes the accepted characters of `text` into the cells from `start` on, as when
+/// typing or pasting, and returns the index of the cell to move to.
+///
+/// A text as long as the code fills the cells from the first one, whatever the
+/// focused cell, e.g. when pasting the code received by mail.
+pub fn fill_cells(
+    cells: &mut [Option<char>],
+    start: usize,
+    text: &str,
+    mode: OtpMode,
+) -> Option<usize> {
+    let accepted: Vec<char> = text.chars().filter(|ch| mode.accepts(*ch)).collect();
+
+    if accepted.is_empty() || cells.is_empty() {
+        return None;
+    }
+
+    let start = match accepted.len() >= cells.len() {
+        true => 0,
+        false => start.min(cells.len() - 1),
+    };
+
+    let written = accepted.len().min(cells.len() - start);
+
+    for (cell, ch) in cells[start..].iter_mut().zip(accepted) {
+        *cell = Some(ch);
+    }
+
+    Some((start + written).min(cells.len() - 1))
+}
+
+/// Character typed into a cell, the input holding the previous character as well
+/// until the event is handled.
+fn typed_text(value: &str, previous: Option<char>) -> String {
+    match previous {
+        Some(previous) if value.chars().count() == 2 => match value.strip_prefix(previous) {
+            Some(typed) => typed.to_string(),
+            None => value.strip_suffix(previous).unwrap_or(value).to_string(),
+        },
+        _ => value.to_string(),
+    }
+}
+
+fn focus_cell(id: &str) {
+    let cell = document()
+        .get_element_by_id(id)
+        .and_then(|element| element.dyn_into::<HtmlInputElement>().ok());
+
+    if let Some(cell) = cell {
+        let _ = cell.focus();
+        cell.select();
+    }
+}
+
+/// Segmented entry of a one time password or a pin, one character per cell.
+///
+/// The control holds the characters of the filled cells, so validators like
+/// `MinLength` apply to the code; `on_complete` is called once every cell is filled.
+#[component]
+pub fn OtpInput(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    /// number of cells
+    #[prop(default = 6)]
+    length: usize,
+    #[prop(optional)] mode: OtpMode,
+    /// shows the characters as dots, e.g. for a pin
+    #[prop(optional)]
+    masked: bool,
+    #[prop(optional, into)] on_complete: Option<Callback<String>>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<String>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "otp-input");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let control_value = control.value;
+
+    let cells: RwSignal<Vec<Option<char>>> = create_rw_signal(code_cells(
+        &control_value.get_untracked().unwrap_or_default(),
+        length,
+    ));
+
+    // the first cell takes the id, so the label points to it
+    let cell_id = {
+        let form_id = form_id.clone();
+
+        move |index: usize| match index {
+            0 => form_id.clone(),
+            index => format!("{form_id}-{index}"),
+        }
+    };
+
+    // reflect control changes (reset, load) into the cells
+    create_effect(move |_| {
+        let code = control_value.get().unwrap_or_default();
+
+        if cells.with_untracked(|cells| cells_code(cells)) != code {
+            cells.set(code_cells(&code, length));
+        }
+    });
+
+    let commit = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |updated: Vec<Option<char>>| {
+            let was_complete = cells.with_untracked(|cells| cells.iter().all(Option::is_some));
+            let is_complete = updated.iter().all(Option::is_some);
+            let code = cells_code(&updated);
+
+            cells.set(updated);
+            control_ref.mark_as_dirty();
+            control_ref.set_value((!code.is_empty()).then(|| code.clone()));
+
+            if is_complete && !was_complete {
+                if let Some(on_complete) = on_complete {
+                    on_complete.call(code);
+                }
+            }
+        }
+    });
+
+    let write = {
+        let cell_id = cell_id.clone();
+
+        move |start: usize, text: &str| {
+            let mut updated = cells.get_untracked();
+
+            if let Some(next) = fill_cells(&mut updated, start, text, mode) {
+                commit.call(updated);
+                focus_cell(&cell_id(next));
+            }
+        }
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // otp specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    let cell_class = move || match visible_err.with(Option::is_some) {
+        true => format!("{CELL_STYLE_CLASS} border-red-600"),
+        false => format!("{CELL_STYLE_CLASS} border-slate-200"),
+    };
+
+    // the feedback region only describes the input while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let render_cell = move |index: usize| {
+        let cell = move || cells.with(|cells| cells.get(index).copied().flatten());
+
+        let on_input = {
+            let write = write.clone();
+
+            move |ev: Event| {
+                let input = event_target::<HtmlInputElement>(&ev);
+                let previous = cells.with_untracked(|cells| cells[index]);
+                let typed = typed_text(&input.value(), previous);
+
+                if typed.is_empty() {
+                    let mut updated = cells.get_untracked();
+                    updated[index] = None;
+                    commit.call(updated);
+                } else {
+                    write(index, &typed);
+                }
+
+                // rejected characters are not kept in the cell
+                let shown = cells.with_untracked(|cells| cells[index]);
+                input.set_value(&shown.map(String::from).unwrap_or_default());
+            }
+        };
+
+        let on_paste = {
+            let write = write.clone();
+
+            move |ev: Event| {
+                let pasted = ev
+                    .dyn_ref::<ClipboardEvent>()
+                    .and_then(ClipboardEvent::clipboard_data)
+                    .and_then(|data| data.get_data("text/plain").ok());
+
+                if let Some(pasted) = pasted {
+                    ev.prevent_default();
+                    write(index, &pasted);
+                }
+            }
+        };
+
+        let on_keydown = {
+            let cell_id = cell_id.clone();
+
+            move |ev: KeyboardEvent| {
+                let last = length - 1;
+
+                let target = match ev.key().as_str() {
+                    "Backspace" => {
+                        // an empty cell clears the previous one
+                        let cleared = match cells.with_untracked(|cells| cells[index].is_some()) {
+                            true => index,
+                            false => index.saturating_sub(1),
+                        };
+
+                        let mut updated = cells.get_untracked();
+
+                        if updated[cleared].is_some() {
+                            updated[cleared] = None;
+                            commit.call(updated);
+                        }
+
+                        cleared
+                    }
+                    "Delete" => {
+                        let mut updated = cells.get_untracked();
+
+                        if updated[index].is_some() {
+                            updated[index] = None;
+                            commit.call(updated);
+                        }
+
+                        index
+                    }
+                    "ArrowLeft" => index.saturating_sub(1),
+                    "ArrowRight" => (index + 1).min(last),
+                    "Home" => 0,
+                    "End" => last,
+                    _ => return,
+                };
+
+                ev.prevent_default();
+                focus_cell(&cell_id(target));
+            }
+        };
+
+        // typing replaces the character of the cell
+        let on_focus = move |ev: FocusEvent| event_target::<HtmlInputElement>(&ev).select();
+
+        view! {
+            <input id=cell_id(index)
+                class=cell_class
+                type=if masked { "password" } else { "text" }
+                inputmode=mode.input_mode()
+                autocomplete=if index == 0 { "one-time-code" } else { "off" }
+                autocapitalize="off"
+                spellcheck="false"
+                aria-label=format!("{} {} of {length}", mode.cell_name(), index + 1)
+                aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                aria-describedby=described_by.clone()
+                prop:value=move || cell().map(String::from).unwrap_or_default()
+                on:focus=on_focus
+                on:input=on_input
+                on:paste=on_paste
+                on:keydown=on_keydown
+                on:blur=on_blur.clone() />
+        }
+    };
+
+    view! {
+        <div class=class_props_by_state>
+            <label id=label_id.clone() for=form_id class="text-xs text-slate-900 mb-1.5">{label}</label>
+            <div class="lumx-otp-cells flex flex-row gap-x-2"
+                role="group"
+                aria-labelledby=label_id>
+                {(0..length).map(render_cell).collect_view()}
+            </div>
+
+            <div class="pt-1">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::otp_input::{cells_code, code_cells, fill_cells, typed_text, OtpMode};
+
+    #[test]
+    fn it_maps_the_code_to_cells() {
+        assert_eq!(code_cells("12", 4), vec![Some('1'), Some('2'), None, None]);
+        assert_eq!(code_cells("123456", 4).len(), 4);
+        assert_eq!(cells_code(&[Some('1'), None, Some('3')]), "13");
+    }
+
+    #[test]
+    fn it_fills_the_cells_while_typing() {
+        let mut cells = code_cells("", 4);
+
+        assert_eq!(fill_cells(&mut cells, 0, "7", OtpMode::Numeric), Some(1));
+        assert_eq!(fill_cells(&mut cells, 1, "a", OtpMode::Numeric), None);
+        assert_eq!(fill_cells(&mut cells, 3, "9", OtpMode::Numeric), Some(3));
+        assert_eq!(cells, vec![Some('7'), None, None, Some('9')]);
+    }
+
+    #[test]
+    fn it_fills_the_cells_from_a_paste() {
+        let mut cells = code_cells("", 4);
+
+        // a full code goes to the first cell, whatever the focused one
+        assert_eq!(
+            fill_cells(&mut cells, 2, "12-34", OtpMode::Numeric),
+            Some(3)
+        );
+        assert_eq!(cells_code(&cells), "1234");
+
+        let mut cells = code_cells("", 4);
+        assert_eq!(
+            fill_cells(&mut cells, 1, "ab", OtpMode::Alphanumeric),
+            Some(3)
+        );
+        assert_eq!(cells, vec![None, Some('a'), Some('b'), None]);
+    }
+
+    #[test]
+    fn it_reads_the_typed_character() {
+        assert_eq!(typed_text("57", Some('5')), "7");
+        assert_eq!(typed_text("75", Some('5')), "7");
+        assert_eq!(typed_text("", Some('5')), "");
+        assert_eq!(typed_text("123456", None), "123456");
+    }
+}
@@ -0,0 +1,77 @@
+use leptos::{
+    component, create_rw_signal,
+    html::{Div, ElementDescriptor},
+    view, Callable, Callback, ChildrenFn, IntoView, NodeRef, RwSignal, Show, SignalGet,
+    SignalGetUntracked, SignalSet,
+};
+use leptos_use::on_click_outside;
+
+/// Open state of the overlay of a field, e.g. a calendar or a list of times.
+#[derive(Clone, Copy)]
+pub struct PopoverState {
+    open: RwSignal<bool>,
+    on_close: Callback<()>,
+}
+
+impl PopoverState {
+    pub fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    pub fn is_open_untracked(&self) -> bool {
+        self.open.get_untracked()
+    }
+
+    pub fn open(&self) {
+        self.open.set(true);
+    }
+
+    /// closes the overlay, resetting what the field keeps while it is open
+    pub fn close(&self) {
+        self.open.set(false);
+        self.on_close.call(());
+    }
+
+    /// closes the overlay from `Escape`, handing the focus back to `target`, e.g. its toggle
+    pub fn dismiss<E>(&self, target: NodeRef<E>)
+    where
+        E: ElementDescriptor + Clone + 'static,
+    {
+        self.close();
+
+        if let Some(target) = target.get_untracked() {
+            let _ = target.into_any().focus();
+        }
+    }
+}
+
+/// State of an overlay closing on clicks outside of `container`, the field and its overlay.
+///
+/// `on_close` runs whenever the overlay closes, e.g. to drop the active option.
+pub fn use_popover<F>(container: NodeRef<Div>, on_close: F) -> PopoverState
+where
+    F: Fn() + 'static,
+{
+    let popover = PopoverState {
+        open: create_rw_signal(false),
+        on_close: Callback::new(move |_| on_close()),
+    };
+
+    let _ = on_click_outside(container, move |_| {
+        if popover.is_open_untracked() {
+            popover.close();
+        }
+    });
+
+    popover
+}
+
+/// Content of an overlay, rendered while it is open.
+#[component]
+pub fn Popover(state: PopoverState, children: ChildrenFn) -> impl IntoView {
+    view! {
+        <Show when=move || state.is_open()>
+            {children()}
+        </Show>
+    }
+}
@@ -0,0 +1,311 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_effect, create_node_ref, create_rw_signal,
+    ev::{FocusEvent, KeyboardEvent, MouseEvent},
+    html::Div,
+    view, Callback, CollectView, IntoView, MaybeSignal, Show, Signal, SignalGet, SignalSet,
+    SignalWith, SignalWithUntracked, ViewFn,
+};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    id::resolve_id,
+    layout::Orientation,
+    listbox::{cycle_active, first_enabled},
+};
+
+const MARK_STYLE_CLASS: &str =
+    "lumx-radio-mark inline-flex shrink-0 items-center justify-center w-4 h-4 rounded-full border bg-white";
+
+/// Radio mark specific properties by state.
+fn mark_class(checked: bool, invalid: bool) -> String {
+    match (checked, invalid) {
+        (true, _) => format!("{MARK_STYLE_CLASS} border-blue-600"),
+        (false, true) => format!("{MARK_STYLE_CLASS} lumx-invalid border-red-600"),
+        (false, false) => format!("{MARK_STYLE_CLASS} border-slate-300"),
+    }
+}
+
+/// How each option of a radio group is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RadioVariant {
+    /// a radio button followed by its label
+    #[default]
+    Default,
+    /// a card showing the icon, label and description, e.g. to pick a plan
+    Card,
+}
+
+#[derive(Clone)]
+pub struct RadioOption<T> {
+    pub value: T,
+    pub label: String,
+    /// secondary text, shown by the card variant
+    pub description: Option<String>,
+    /// shown by the card variant
+    pub icon: Option<ViewFn>,
+    pub disabled: bool,
+}
+
+impl<T> RadioOption<T> {
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            description: None,
+            icon: None,
+            disabled: false,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<ViewFn>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Single choice among a few options, all of them visible.
+///
+/// Follows the WAI-ARIA radio group pattern: the group is a single tab stop and the
+/// arrow keys move the focus and the selection to the next enabled option, wrapping around.
+#[component]
+pub fn RadioGroup<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] orientation: Orientation,
+    #[prop(optional)] variant: RadioVariant,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    #[prop(into)] options: MaybeSignal<Vec<RadioOption<T>>>,
+    control: Arc<FormControl<T>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let form_id = resolve_id(id, "radio-group");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let control_value = control.value;
+
+    let options = Signal::derive(move || options.get());
+    // option asked to take the focus after a keyboard move
+    let focus_request = create_rw_signal(None::<usize>);
+
+    let disabled_flags = move || {
+        options.with(|options| {
+            options
+                .iter()
+                .map(|option| option.disabled)
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let checked_idx = move || {
+        control_value.with(|value| {
+            options.with(|options| {
+                value
+                    .as_ref()
+                    .and_then(|value| options.iter().position(|option| option.value == *value))
+            })
+        })
+    };
+
+    // the checked option holds the tab stop, the first enabled one when none is checked
+    let tab_stop = move || {
+        let disabled = disabled_flags();
+
+        checked_idx()
+            .filter(|idx| !disabled[*idx])
+            .or_else(|| first_enabled(&disabled))
+    };
+
+    let check = {
+        let control_ref = Arc::clone(&control);
+
+        move |idx: usize| {
+            let Some(option) = options.with_untracked(|options| options.get(idx).cloned()) else {
+                return;
+            };
+
+            if option.disabled || checked_idx() == Some(idx) {
+                return;
+            }
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value(Some(option.value));
+        }
+    };
+
+    let on_keydown = {
+        let check = check.clone();
+
+        move |ev: KeyboardEvent| {
+            let delta = match ev.key().as_str() {
+                "ArrowDown" | "ArrowRight" => 1,
+                "ArrowUp" | "ArrowLeft" => -1,
+                " " => {
+                    if let Some(idx) = tab_stop() {
+                        check(idx);
+                    }
+
+                    ev.prevent_default();
+                    return;
+                }
+                _ => return,
+            };
+
+            if let Some(idx) = cycle_active(&disabled_flags(), tab_stop(), delta) {
+                check(idx);
+                focus_request.set(Some(idx));
+            }
+
+            ev.prevent_default();
+        }
+    };
+
+    let on_focusout = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // the feedback region only describes the group while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let layout_class = match orientation {
+        Orientation::Vertical => "lumx-radio-options flex flex-col gap-y-2",
+        Orientation::Horizontal => "lumx-radio-options flex flex-row flex-wrap gap-x-4 gap-y-2",
+    };
+
+    let render_option = move |idx: usize, option: RadioOption<T>| {
+        let option_ref = create_node_ref::<Div>();
+        let is_checked = move || checked_idx() == Some(idx);
+        let disabled = option.disabled;
+        let check = check.clone();
+
+        // move the focus along with the keyboard selection
+        create_effect(move |_| {
+            if focus_request.get() == Some(idx) {
+                if let Some(option) = option_ref.get_untracked() {
+                    let _ = option.focus();
+                }
+            }
+        });
+
+        let option_class = move || {
+            let base = match variant {
+                RadioVariant::Default => "lumx-radio-option inline-flex items-center gap-x-2 text-sm text-slate-900 outline-none focus-visible:ring-2 focus-visible:ring-blue-600/40 rounded-md",
+                RadioVariant::Card => "lumx-radio-option lumx-card flex flex-row items-start gap-x-3 rounded-md border bg-white py-4 px-4 outline-none focus-visible:ring-2 focus-visible:ring-blue-600/40",
+            };
+
+            let state = match (variant, is_checked()) {
+                (RadioVariant::Card, true) => "border-blue-600",
+                (RadioVariant::Card, false) => "border-slate-200",
+                (RadioVariant::Default, _) => "",
+            };
+
+            let interaction = match disabled {
+                true => "opacity-50 cursor-not-allowed",
+                false => "cursor-pointer",
+            };
+
+            format!("{base} {state} {interaction}")
+        };
+
+        let mark = view! {
+            <span class=move || mark_class(is_checked(), visible_err.with(Option::is_some))>
+                <Show when=is_checked>
+                    <span class="w-2 h-2 rounded-full bg-blue-600"></span>
+                </Show>
+            </span>
+        };
+
+        let content = match variant {
+            RadioVariant::Default => view! { <span>{option.label}</span> }.into_view(),
+            RadioVariant::Card => view! {
+                {option.icon.map(|icon| view! {
+                    <span class="lumx-radio-icon shrink-0 text-slate-900">{icon.run()}</span>
+                })}
+                <span class="flex flex-col grow">
+                    <span class="text-sm font-semibold text-slate-900">{option.label}</span>
+                    {option.description.map(|description| view! {
+                        <span class="text-sm text-slate-500">{description}</span>
+                    })}
+                </span>
+            }
+            .into_view(),
+        };
+
+        // the card variant shows the mark last, next to the top right corner
+        let (leading_mark, trailing_mark) = match variant {
+            RadioVariant::Default => (Some(mark), None),
+            RadioVariant::Card => (None, Some(mark)),
+        };
+
+        view! {
+            <div node_ref=option_ref
+                role="radio"
+                aria-checked=move || is_checked().to_string()
+                aria-disabled=disabled.then_some("true")
+                tabindex=move || if tab_stop() == Some(idx) { "0" } else { "-1" }
+                class=option_class
+                on:click=move |_ev: MouseEvent| check(idx)>
+                {leading_mark}
+                {content}
+                {trailing_mark}
+            </div>
+        }
+    };
+
+    view! {
+        <div class="lumx-radio-group flex flex-col mb-2.5">
+            <div id=label_id.clone() class="text-xs text-slate-900 mb-1">{label}</div>
+
+            <div
+                id=form_id
+                role="radiogroup"
+                aria-labelledby=label_id
+                aria-orientation=orientation.as_str()
+                aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                aria-describedby=described_by
+                class=layout_class
+                on:keydown=on_keydown
+                on:focusout=on_focusout>
+                {move || {
+                    options
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(idx, option)| render_option.clone()(idx, option))
+                        .collect_view()
+                }}
+            </div>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </div>
+    }
+}
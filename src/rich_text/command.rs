@@ -0,0 +1,87 @@
+//! Formatting commands of the editor toolbar and their keyboard shortcuts.
+
+use super::document::{BlockKind, DocSelection, InlineMark, RichDocument};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorCommand {
+    ToggleMark(InlineMark),
+    ToggleBlock(BlockKind),
+    /// asks for the address of a link over the selection
+    Link,
+}
+
+impl EditorCommand {
+    /// Whether the command is in effect for the selection, e.g. to press its toolbar button.
+    pub fn is_active(&self, document: &RichDocument, selection: DocSelection) -> bool {
+        match self {
+            EditorCommand::ToggleMark(mark) => document.has_mark(selection, *mark),
+            EditorCommand::ToggleBlock(kind) => document.block_kind(selection) == Some(*kind),
+            EditorCommand::Link => document.link_at(selection.start()).is_some(),
+        }
+    }
+
+    /// Applies the formatting commands, links need an address, see `RichDocument::set_link`.
+    pub fn apply(&self, document: &mut RichDocument, selection: DocSelection) {
+        match self {
+            EditorCommand::ToggleMark(mark) => document.toggle_mark(selection, *mark),
+            EditorCommand::ToggleBlock(kind) => document.toggle_block_kind(selection, *kind),
+            EditorCommand::Link => {}
+        }
+    }
+}
+
+/// Command of a key pressed with `Ctrl`, or `Cmd` on Apple devices.
+///
+/// `code` is the physical key, as the printed key changes with `Shift` and `Alt`:
+/// `B` bold, `I` italic, `K` link, `Alt+1` to `Alt+3` headings, `Shift+7` numbered
+/// list and `Shift+8` bullet list.
+pub fn shortcut_command(code: &str, shift: bool, alt: bool) -> Option<EditorCommand> {
+    let command = match (code, shift, alt) {
+        ("KeyB", false, false) => EditorCommand::ToggleMark(InlineMark::Bold),
+        ("KeyI", false, false) => EditorCommand::ToggleMark(InlineMark::Italic),
+        ("KeyK", false, false) => EditorCommand::Link,
+        ("Digit1", false, true) => EditorCommand::ToggleBlock(BlockKind::heading(1)),
+        ("Digit2", false, true) => EditorCommand::ToggleBlock(BlockKind::heading(2)),
+        ("Digit3", false, true) => EditorCommand::ToggleBlock(BlockKind::heading(3)),
+        ("Digit7", true, false) => EditorCommand::ToggleBlock(BlockKind::OrderedItem),
+        ("Digit8", true, false) => EditorCommand::ToggleBlock(BlockKind::BulletItem),
+        _ => return None,
+    };
+
+    Some(command)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rich_text::{
+        command::{shortcut_command, EditorCommand},
+        document::{BlockKind, DocPosition, DocSelection, InlineMark, RichDocument},
+    };
+
+    #[test]
+    fn it_maps_the_shortcuts() {
+        assert_eq!(
+            shortcut_command("KeyB", false, false),
+            Some(EditorCommand::ToggleMark(InlineMark::Bold))
+        );
+        assert_eq!(
+            shortcut_command("Digit2", false, true),
+            Some(EditorCommand::ToggleBlock(BlockKind::Heading(2)))
+        );
+        assert_eq!(shortcut_command("KeyB", true, false), None);
+        assert_eq!(shortcut_command("KeyZ", false, false), None);
+    }
+
+    #[test]
+    fn it_applies_the_commands() {
+        let mut doc = RichDocument::from_plain_text("item");
+        let selection = DocSelection::collapsed(DocPosition::new(0, 2));
+        let command = EditorCommand::ToggleBlock(BlockKind::OrderedItem);
+
+        command.apply(&mut doc, selection);
+        assert!(command.is_active(&doc, selection));
+
+        command.apply(&mut doc, selection);
+        assert!(!command.is_active(&doc, selection));
+    }
+}
@@ -0,0 +1,656 @@
+//! Structured content of the rich text editor, edited without a browser.
+
+/// Deepest heading offered by the editor, deeper ones are imported at this level.
+pub const MAX_HEADING_LEVEL: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineMark {
+    Bold,
+    Italic,
+}
+
+/// Inline formatting of a run of text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextStyle {
+    pub bold: bool,
+    pub italic: bool,
+    /// target of the link, only safe addresses are kept, see `safe_href`
+    pub link: Option<String>,
+}
+
+impl TextStyle {
+    pub fn has(&self, mark: InlineMark) -> bool {
+        match mark {
+            InlineMark::Bold => self.bold,
+            InlineMark::Italic => self.italic,
+        }
+    }
+
+    pub fn set(&mut self, mark: InlineMark, on: bool) {
+        match mark {
+            InlineMark::Bold => self.bold = on,
+            InlineMark::Italic => self.italic = on,
+        }
+    }
+}
+
+/// Text sharing the same style.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextRun {
+    pub text: String,
+    pub style: TextStyle,
+}
+
+impl TextRun {
+    pub fn new(text: impl Into<String>, style: TextStyle) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::new(text, TextStyle::default())
+    }
+
+    /// length in chars, the unit of the document positions
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlockKind {
+    #[default]
+    Paragraph,
+    /// level from 1 to `MAX_HEADING_LEVEL`
+    Heading(u8),
+    BulletItem,
+    OrderedItem,
+}
+
+impl BlockKind {
+    pub fn heading(level: u8) -> Self {
+        BlockKind::Heading(level.clamp(1, MAX_HEADING_LEVEL))
+    }
+
+    pub fn is_list_item(&self) -> bool {
+        matches!(self, BlockKind::BulletItem | BlockKind::OrderedItem)
+    }
+}
+
+/// Paragraph, heading or list item.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Block {
+    pub kind: BlockKind,
+    pub runs: Vec<TextRun>,
+}
+
+impl Block {
+    pub fn new(kind: BlockKind, runs: Vec<TextRun>) -> Self {
+        Self { kind, runs }
+    }
+
+    pub fn paragraph(text: impl Into<String>) -> Self {
+        Self::new(BlockKind::Paragraph, vec![TextRun::plain(text)])
+    }
+
+    pub fn text(&self) -> String {
+        self.runs.iter().map(|run| run.text.as_str()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.runs.iter().map(TextRun::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.iter().all(TextRun::is_empty)
+    }
+
+    /// Drops the empty runs and merges the neighbours sharing a style.
+    pub fn normalize(&mut self) {
+        let mut runs: Vec<TextRun> = Vec::with_capacity(self.runs.len());
+
+        for run in self.runs.drain(..).filter(|run| !run.is_empty()) {
+            match runs.last_mut() {
+                Some(last) if last.style == run.style => last.text.push_str(&run.text),
+                _ => runs.push(run),
+            }
+        }
+
+        self.runs = runs;
+    }
+
+    /// Style of the char before the offset, or of the first char at the start of the block.
+    pub fn style_at(&self, offset: usize) -> TextStyle {
+        let mut start = 0;
+
+        for run in &self.runs {
+            let end = start + run.len();
+
+            if offset <= end && (offset > start || start == 0) && !run.is_empty() {
+                return run.style.clone();
+            }
+
+            start = end;
+        }
+
+        TextStyle::default()
+    }
+
+    /// Splits the run holding the offset so that a run starts there, returns its index.
+    fn split_at(&mut self, offset: usize) -> usize {
+        let mut start = 0;
+
+        for idx in 0..self.runs.len() {
+            let len = self.runs[idx].len();
+
+            if offset == start {
+                return idx;
+            }
+
+            if offset < start + len {
+                let run = &mut self.runs[idx];
+                let byte = run
+                    .text
+                    .char_indices()
+                    .nth(offset - start)
+                    .map_or(run.text.len(), |(byte, _)| byte);
+                let tail = TextRun::new(run.text.split_off(byte), run.style.clone());
+
+                self.runs.insert(idx + 1, tail);
+                return idx + 1;
+            }
+
+            start += len;
+        }
+
+        self.runs.len()
+    }
+
+    /// Removes and returns the runs after the offset.
+    fn split_off(&mut self, offset: usize) -> Vec<TextRun> {
+        let idx = self.split_at(offset);
+
+        self.runs.split_off(idx)
+    }
+}
+
+/// Place between two chars of a block, counted in chars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DocPosition {
+    pub block: usize,
+    pub offset: usize,
+}
+
+impl DocPosition {
+    pub fn new(block: usize, offset: usize) -> Self {
+        Self { block, offset }
+    }
+}
+
+/// Selected content, from where the selection started to where it ends, in either order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocSelection {
+    pub anchor: DocPosition,
+    pub focus: DocPosition,
+}
+
+impl DocSelection {
+    pub fn new(anchor: DocPosition, focus: DocPosition) -> Self {
+        Self { anchor, focus }
+    }
+
+    pub fn collapsed(position: DocPosition) -> Self {
+        Self::new(position, position)
+    }
+
+    pub fn start(&self) -> DocPosition {
+        self.anchor.min(self.focus)
+    }
+
+    pub fn end(&self) -> DocPosition {
+        self.anchor.max(self.focus)
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.anchor == self.focus
+    }
+}
+
+/// Blocks of rich text, the value of the rich text editor.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichDocument {
+    pub blocks: Vec<Block>,
+}
+
+impl RichDocument {
+    pub fn new(blocks: Vec<Block>) -> Self {
+        Self { blocks }
+    }
+
+    /// One paragraph per line.
+    pub fn from_plain_text(text: &str) -> Self {
+        Self::new(text.lines().map(Block::paragraph).collect())
+    }
+
+    /// Text of the blocks, one per line.
+    pub fn plain_text(&self) -> String {
+        self.blocks
+            .iter()
+            .map(Block::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// holds no text at all
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(Block::is_empty)
+    }
+
+    pub fn normalize(&mut self) {
+        self.blocks.iter_mut().for_each(Block::normalize);
+    }
+
+    /// Position after the last char.
+    pub fn end(&self) -> DocPosition {
+        match self.blocks.last() {
+            Some(block) => DocPosition::new(self.blocks.len() - 1, block.len()),
+            None => DocPosition::default(),
+        }
+    }
+
+    /// Keeps the position within the blocks and their text.
+    pub fn clamp(&self, position: DocPosition) -> DocPosition {
+        if self.blocks.is_empty() {
+            return DocPosition::default();
+        }
+
+        let block = position.block.min(self.blocks.len() - 1);
+
+        DocPosition::new(block, position.offset.min(self.blocks[block].len()))
+    }
+
+    /// Whether the selected text has the mark, or the text before the caret when collapsed.
+    pub fn has_mark(&self, selection: DocSelection, mark: InlineMark) -> bool {
+        let (start, end) = (self.clamp(selection.start()), self.clamp(selection.end()));
+
+        if start == end {
+            return self
+                .blocks
+                .get(start.block)
+                .is_some_and(|block| block.style_at(start.offset).has(mark));
+        }
+
+        let mut selected = self.selected_runs(start, end).peekable();
+
+        selected.peek().is_some() && selected.all(|run| run.style.has(mark))
+    }
+
+    /// Removes the mark when all the selected text has it, adds it otherwise.
+    pub fn toggle_mark(&mut self, selection: DocSelection, mark: InlineMark) {
+        let on = !self.has_mark(selection, mark);
+
+        self.update_style(selection, |style| style.set(mark, on));
+    }
+
+    /// Links the selected text, or removes its links. Returns false for an unsafe address.
+    pub fn set_link(&mut self, selection: DocSelection, href: Option<&str>) -> bool {
+        let link = match href {
+            Some(href) => match safe_href(href) {
+                Some(link) => Some(link),
+                None => return false,
+            },
+            None => None,
+        };
+
+        self.update_style(selection, |style| style.link = link.clone());
+
+        true
+    }
+
+    pub fn link_at(&self, position: DocPosition) -> Option<String> {
+        let position = self.clamp(position);
+
+        self.blocks
+            .get(position.block)
+            .and_then(|block| block.style_at(position.offset).link)
+    }
+
+    /// Kind shared by all the selected blocks.
+    pub fn block_kind(&self, selection: DocSelection) -> Option<BlockKind> {
+        let (start, end) = (self.clamp(selection.start()), self.clamp(selection.end()));
+        let kind = self.blocks.get(start.block)?.kind;
+
+        self.blocks[start.block..=end.block]
+            .iter()
+            .all(|block| block.kind == kind)
+            .then_some(kind)
+    }
+
+    /// Turns the selected blocks into the kind, or back into paragraphs when they all are.
+    pub fn toggle_block_kind(&mut self, selection: DocSelection, kind: BlockKind) {
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::default());
+        }
+
+        let (start, end) = (self.clamp(selection.start()), self.clamp(selection.end()));
+        let kind = match self.block_kind(selection) == Some(kind) {
+            true => BlockKind::Paragraph,
+            false => kind,
+        };
+
+        for block in &mut self.blocks[start.block..=end.block] {
+            block.kind = kind;
+        }
+    }
+
+    /// Removes the selected content, joining the first and last blocks.
+    pub fn delete_range(&mut self, selection: DocSelection) -> DocPosition {
+        let (start, end) = (self.clamp(selection.start()), self.clamp(selection.end()));
+
+        if start == end {
+            return start;
+        }
+
+        let tail = self.blocks[end.block].split_off(end.offset);
+        self.blocks[start.block].split_off(start.offset);
+        self.blocks.drain(start.block + 1..=end.block);
+
+        let block = &mut self.blocks[start.block];
+        block.runs.extend(tail);
+        block.normalize();
+
+        start
+    }
+
+    /// Breaks the block at the position, returns the start of the new block.
+    ///
+    /// Like in most editors, breaking an empty list item ends the list and the block after
+    /// a heading is a paragraph.
+    pub fn split_block(&mut self, position: DocPosition) -> DocPosition {
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::default());
+        }
+
+        let position = self.clamp(position);
+        let block = &mut self.blocks[position.block];
+
+        if block.kind.is_list_item() && block.is_empty() {
+            block.kind = BlockKind::Paragraph;
+            return position;
+        }
+
+        let tail = block.split_off(position.offset);
+        let kind = match block.kind {
+            BlockKind::Heading(_) if tail.is_empty() => BlockKind::Paragraph,
+            kind => kind,
+        };
+
+        block.normalize();
+        self.blocks
+            .insert(position.block + 1, Block::new(kind, tail));
+
+        DocPosition::new(position.block + 1, 0)
+    }
+
+    /// Inserts the blocks of the fragment, the first one joining the block at the
+    /// position. Returns the position after the inserted content.
+    pub fn insert_fragment(
+        &mut self,
+        position: DocPosition,
+        fragment: &RichDocument,
+    ) -> DocPosition {
+        let Some((first, rest)) = fragment.blocks.split_first() else {
+            return position;
+        };
+
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::new(first.kind, vec![]));
+        }
+
+        let position = self.clamp(position);
+        let tail = self.blocks[position.block].split_off(position.offset);
+        self.blocks[position.block]
+            .runs
+            .extend(first.runs.iter().cloned());
+
+        let index = position.block + rest.len();
+        self.blocks
+            .splice(position.block + 1..position.block + 1, rest.iter().cloned());
+
+        let block = &mut self.blocks[index];
+        let end = DocPosition::new(index, block.len());
+        block.runs.extend(tail);
+
+        self.normalize();
+
+        end
+    }
+
+    /// Replaces the selected content with the fragment, as done when pasting.
+    pub fn replace_selection(
+        &mut self,
+        selection: DocSelection,
+        fragment: &RichDocument,
+    ) -> DocPosition {
+        let position = self.delete_range(selection);
+
+        self.insert_fragment(position, fragment)
+    }
+
+    fn selected_runs(
+        &self,
+        start: DocPosition,
+        end: DocPosition,
+    ) -> impl Iterator<Item = &TextRun> {
+        self.blocks[start.block..=end.block]
+            .iter()
+            .enumerate()
+            .flat_map(move |(idx, block)| {
+                let from = if idx == 0 { start.offset } else { 0 };
+                let to = match start.block + idx == end.block {
+                    true => end.offset,
+                    false => block.len(),
+                };
+                let mut run_start = 0;
+
+                block.runs.iter().filter(move |run| {
+                    let run_end = run_start + run.len();
+                    let overlaps = run_start < to && from < run_end;
+
+                    run_start = run_end;
+                    overlaps
+                })
+            })
+    }
+
+    fn update_style(&mut self, selection: DocSelection, update: impl Fn(&mut TextStyle)) {
+        let (start, end) = (self.clamp(selection.start()), self.clamp(selection.end()));
+
+        if start == end {
+            return;
+        }
+
+        for idx in start.block..=end.block {
+            let block = &mut self.blocks[idx];
+            let from = if idx == start.block { start.offset } else { 0 };
+            let to = if idx == end.block {
+                end.offset
+            } else {
+                block.len()
+            };
+
+            if from == to {
+                continue;
+            }
+
+            let first = block.split_at(from);
+            let last = block.split_at(to);
+
+            block.runs[first..last]
+                .iter_mut()
+                .for_each(|run| update(&mut run.style));
+            block.normalize();
+        }
+    }
+}
+
+/// Keeps web, mail and phone links as well as relative ones, drops scripts and data.
+pub fn safe_href(href: &str) -> Option<String> {
+    let href = href.trim();
+    let scheme = href
+        .split_once(':')
+        .map(|(scheme, _)| scheme)
+        .filter(|scheme| !scheme.contains(['/', '?', '#']));
+
+    let safe = match scheme {
+        Some(scheme) => ["http", "https", "mailto", "tel"]
+            .iter()
+            .any(|safe| scheme.eq_ignore_ascii_case(safe)),
+        None => !href.is_empty(),
+    };
+
+    safe.then(|| href.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rich_text::document::{
+        safe_href, Block, BlockKind, DocPosition, DocSelection, InlineMark, RichDocument, TextRun,
+        TextStyle,
+    };
+
+    fn selection(from: (usize, usize), to: (usize, usize)) -> DocSelection {
+        DocSelection::new(
+            DocPosition::new(from.0, from.1),
+            DocPosition::new(to.0, to.1),
+        )
+    }
+
+    fn bold(text: &str) -> TextRun {
+        TextRun::new(
+            text,
+            TextStyle {
+                bold: true,
+                ..TextStyle::default()
+            },
+        )
+    }
+
+    #[test]
+    fn it_toggles_marks_over_the_selection() {
+        let mut doc = RichDocument::new(vec![Block::paragraph("hello world")]);
+
+        doc.toggle_mark(selection((0, 6), (0, 11)), InlineMark::Bold);
+        assert_eq!(
+            doc.blocks[0].runs,
+            vec![TextRun::plain("hello "), bold("world")]
+        );
+        assert!(doc.has_mark(selection((0, 6), (0, 8)), InlineMark::Bold));
+        assert!(!doc.has_mark(selection((0, 0), (0, 8)), InlineMark::Bold));
+
+        // partially marked text is marked first, then unmarked
+        doc.toggle_mark(selection((0, 0), (0, 11)), InlineMark::Bold);
+        assert_eq!(doc.blocks[0].runs, vec![bold("hello world")]);
+
+        doc.toggle_mark(selection((0, 11), (0, 0)), InlineMark::Bold);
+        assert_eq!(doc.blocks[0].runs, vec![TextRun::plain("hello world")]);
+    }
+
+    #[test]
+    fn it_marks_across_blocks() {
+        let mut doc = RichDocument::new(vec![Block::paragraph("añejo"), Block::paragraph("rum")]);
+
+        doc.toggle_mark(selection((0, 3), (1, 1)), InlineMark::Italic);
+
+        assert!(doc.blocks[0].runs[1].style.italic);
+        assert_eq!(doc.blocks[0].runs[1].text, "jo");
+        assert_eq!(doc.blocks[1].runs[0].text, "r");
+        assert!(doc.has_mark(selection((0, 3), (1, 1)), InlineMark::Italic));
+    }
+
+    #[test]
+    fn it_only_keeps_safe_links() {
+        let mut doc = RichDocument::new(vec![Block::paragraph("docs")]);
+
+        assert!(!doc.set_link(selection((0, 0), (0, 4)), Some("javascript:alert(1)")));
+        assert!(doc.set_link(selection((0, 0), (0, 4)), Some("https://leptos.dev")));
+        assert_eq!(
+            doc.link_at(DocPosition::new(0, 2)),
+            Some(String::from("https://leptos.dev"))
+        );
+
+        assert_eq!(
+            safe_href("/guide#forms"),
+            Some(String::from("/guide#forms"))
+        );
+        assert_eq!(safe_href(" JavaScript:void(0)"), None);
+        assert_eq!(safe_href("data:text/html,x"), None);
+    }
+
+    #[test]
+    fn it_toggles_the_block_kinds() {
+        let mut doc = RichDocument::from_plain_text("one\ntwo\nthree");
+
+        doc.toggle_block_kind(selection((0, 1), (1, 0)), BlockKind::BulletItem);
+        assert_eq!(
+            doc.block_kind(selection((0, 0), (1, 0))),
+            Some(BlockKind::BulletItem)
+        );
+        assert_eq!(doc.block_kind(selection((1, 0), (2, 0))), None);
+
+        doc.toggle_block_kind(selection((0, 0), (1, 0)), BlockKind::BulletItem);
+        assert_eq!(doc.blocks[1].kind, BlockKind::Paragraph);
+    }
+
+    #[test]
+    fn it_splits_and_joins_blocks() {
+        let mut doc = RichDocument::new(vec![Block::new(
+            BlockKind::heading(1),
+            vec![TextRun::plain("Title")],
+        )]);
+
+        let position = doc.split_block(doc.end());
+        assert_eq!(position, DocPosition::new(1, 0));
+        assert_eq!(doc.blocks[1].kind, BlockKind::Paragraph);
+
+        let mut doc = RichDocument::from_plain_text("hello\nworld");
+        let position = doc.delete_range(selection((0, 4), (1, 1)));
+
+        assert_eq!(position, DocPosition::new(0, 4));
+        assert_eq!(doc.plain_text(), "hellorld");
+    }
+
+    #[test]
+    fn it_ends_lists_on_empty_items() {
+        let mut doc = RichDocument::new(vec![Block::new(BlockKind::BulletItem, vec![])]);
+
+        doc.split_block(DocPosition::default());
+
+        assert_eq!(doc.blocks, vec![Block::new(BlockKind::Paragraph, vec![])]);
+    }
+
+    #[test]
+    fn it_replaces_the_selection_with_a_fragment() {
+        let mut doc = RichDocument::from_plain_text("before after");
+        let fragment = RichDocument::new(vec![
+            Block::new(BlockKind::Paragraph, vec![bold("one")]),
+            Block::new(BlockKind::BulletItem, vec![TextRun::plain("two")]),
+        ]);
+
+        let position = doc.replace_selection(selection((0, 7), (0, 12)), &fragment);
+
+        assert_eq!(position, DocPosition::new(1, 3));
+        assert_eq!(doc.plain_text(), "before one\ntwo");
+        assert_eq!(
+            doc.blocks[0].runs,
+            vec![TextRun::plain("before "), bold("one")]
+        );
+        assert_eq!(doc.blocks[1].kind, BlockKind::BulletItem);
+    }
+}
@@ -0,0 +1,611 @@
+//! HTML import and export of the rich text documents.
+//!
+//! Importing doubles as sanitization: only the text, the supported blocks and marks and the
+//! safe links survive, everything else, scripts, styles, attributes and event handlers
+//! included, is dropped.
+
+use super::document::{safe_href, Block, BlockKind, RichDocument, TextRun, TextStyle};
+
+pub fn to_html(document: &RichDocument) -> String {
+    let mut html = String::new();
+    let mut open_list: Option<&str> = None;
+
+    for block in &document.blocks {
+        let list = match block.kind {
+            BlockKind::BulletItem => Some("ul"),
+            BlockKind::OrderedItem => Some("ol"),
+            _ => None,
+        };
+
+        if open_list != list {
+            if let Some(tag) = open_list {
+                html.push_str(&format!("</{tag}>"));
+            }
+
+            if let Some(tag) = list {
+                html.push_str(&format!("<{tag}>"));
+            }
+
+            open_list = list;
+        }
+
+        let tag = match block.kind {
+            BlockKind::Paragraph => String::from("p"),
+            BlockKind::Heading(level) => format!("h{level}"),
+            BlockKind::BulletItem | BlockKind::OrderedItem => String::from("li"),
+        };
+
+        html.push_str(&format!("<{tag}>"));
+
+        match block.is_empty() {
+            // keeps the empty line editable
+            true => html.push_str("<br>"),
+            false => push_runs(&mut html, block),
+        }
+
+        html.push_str(&format!("</{tag}>"));
+    }
+
+    if let Some(tag) = open_list {
+        html.push_str(&format!("</{tag}>"));
+    }
+
+    html
+}
+
+fn push_runs(html: &mut String, block: &Block) {
+    let len = block.len();
+    let mut offset = 0;
+    let mut previous_space = false;
+
+    for run in &block.runs {
+        let mut text = String::new();
+
+        // spaces that HTML would collapse are kept as non-breaking ones
+        for ch in run.text.chars() {
+            let space = ch == ' ';
+
+            match ch {
+                ' ' if previous_space || offset == 0 || offset == len - 1 => {
+                    text.push_str("&nbsp;")
+                }
+                '&' => text.push_str("&amp;"),
+                '<' => text.push_str("&lt;"),
+                '>' => text.push_str("&gt;"),
+                ch => text.push(ch),
+            }
+
+            previous_space = space;
+            offset += 1;
+        }
+
+        if run.style.italic {
+            text = format!("<em>{text}</em>");
+        }
+
+        if run.style.bold {
+            text = format!("<strong>{text}</strong>");
+        }
+
+        if let Some(link) = &run.style.link {
+            text = format!("<a href=\"{}\">{text}</a>", escape_attribute(link));
+        }
+
+        html.push_str(&text);
+    }
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Parses and sanitizes HTML, e.g. pasted content or the content of the editor.
+///
+/// Unknown elements are unwrapped, `div` elements and line breaks start new paragraphs and
+/// headings deeper than `MAX_HEADING_LEVEL` are kept at that level.
+pub fn from_html(html: &str) -> RichDocument {
+    let mut parser = HtmlImport::default();
+
+    for token in tokenize(html) {
+        parser.push(token);
+    }
+
+    parser.finish()
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Open {
+        name: String,
+        attributes: Vec<(String, String)>,
+    },
+    Close(String),
+    Text(String),
+}
+
+/// elements whose content is never shown
+const SKIPPED: [&str; 7] = [
+    "script", "style", "head", "title", "template", "noscript", "iframe",
+];
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    let mut skip_until: Option<String> = None;
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            if skip_until.is_none() {
+                tokens.push(Token::Text(rest.to_string()));
+            }
+            break;
+        };
+
+        if lt > 0 && skip_until.is_none() {
+            tokens.push(Token::Text(rest[..lt].to_string()));
+        }
+
+        rest = &rest[lt..];
+
+        // comments, doctypes and processing instructions
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let closing = rest.starts_with("</");
+        let name_start = if closing { 2 } else { 1 };
+        let name_len = rest[name_start..]
+            .find(|ch: char| !ch.is_ascii_alphanumeric())
+            .unwrap_or(rest.len() - name_start);
+
+        // a lone `<` is text
+        if name_len == 0 {
+            if skip_until.is_none() {
+                tokens.push(Token::Text(String::from("<")));
+            }
+            rest = &rest[1..];
+            continue;
+        }
+
+        let name = rest[name_start..name_start + name_len].to_ascii_lowercase();
+        let (attributes, after) = parse_attributes(&rest[name_start + name_len..]);
+        rest = after;
+
+        match (&skip_until, closing) {
+            (Some(skipped), true) if *skipped == name => skip_until = None,
+            (Some(_), _) => {}
+            (None, true) => tokens.push(Token::Close(name)),
+            (None, false) if SKIPPED.contains(&name.as_str()) => skip_until = Some(name),
+            (None, false) => tokens.push(Token::Open { name, attributes }),
+        }
+    }
+
+    tokens
+}
+
+/// Reads the attributes up to the end of the tag, returns them with the remaining input.
+fn parse_attributes(input: &str) -> (Vec<(String, String)>, &str) {
+    let mut attributes = Vec::new();
+    let mut rest = input;
+
+    loop {
+        rest = rest.trim_start_matches(|ch: char| ch.is_whitespace() || ch == '/');
+
+        if rest.is_empty() {
+            return (attributes, rest);
+        }
+
+        if let Some(after) = rest.strip_prefix('>') {
+            return (attributes, after);
+        }
+
+        let name_len = rest
+            .find(|ch: char| ch.is_whitespace() || ch == '=' || ch == '>' || ch == '/')
+            .unwrap_or(rest.len())
+            .max(1);
+        let name = rest[..name_len].to_ascii_lowercase();
+        rest = rest[name_len..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+
+                match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let end = after[1..].find(quote).map_or(after.len(), |end| end + 1);
+                        rest = after.get(end + 1..).unwrap_or("");
+                        decode_entities(&after[1..end])
+                    }
+                    _ => {
+                        let end = after
+                            .find(|ch: char| ch.is_whitespace() || ch == '>')
+                            .unwrap_or(after.len());
+                        rest = &after[end..];
+                        decode_entities(&after[..end])
+                    }
+                }
+            }
+            None => String::new(),
+        };
+
+        attributes.push((name, value));
+    }
+}
+
+/// Decodes the character references, `&nbsp;` becomes a non-breaking space.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let reference = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+
+        let ch = reference.and_then(|reference| match reference {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => match reference
+                .strip_prefix("#x")
+                .or_else(|| reference.strip_prefix("#X"))
+            {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => reference
+                    .strip_prefix('#')
+                    .and_then(|number| number.parse().ok())
+                    .and_then(char::from_u32),
+            },
+        });
+
+        match (ch, reference) {
+            (Some(ch), Some(reference)) => {
+                decoded.push(ch);
+                rest = &rest[reference.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Inline element with the formatting it brings.
+struct InlineElement {
+    name: String,
+    bold: bool,
+    italic: bool,
+    link: Option<String>,
+}
+
+#[derive(Default)]
+struct HtmlImport {
+    blocks: Vec<Block>,
+    current: Option<Block>,
+    /// open block elements with the kind of their content
+    block_stack: Vec<(String, BlockKind)>,
+    /// open lists, `ol` or `ul`
+    list_stack: Vec<String>,
+    inline_stack: Vec<InlineElement>,
+    /// the current block ends with a space coming from collapsed whitespace
+    collapsible_space: bool,
+}
+
+impl HtmlImport {
+    fn push(&mut self, token: Token) {
+        match token {
+            Token::Text(text) => self.push_text(&text),
+            Token::Open { name, attributes } => self.open(name, attributes),
+            Token::Close(name) => self.close(&name),
+        }
+    }
+
+    fn open(&mut self, name: String, attributes: Vec<(String, String)>) {
+        let attribute = |key: &str| {
+            attributes
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.as_str())
+        };
+
+        match name.as_str() {
+            "p" | "div" | "blockquote" | "pre" => self.open_block(name, BlockKind::Paragraph),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = name[1..].parse().unwrap_or(1);
+                self.open_block(name, BlockKind::heading(level))
+            }
+            "li" => {
+                let kind = match self.list_stack.last().map(String::as_str) {
+                    Some("ol") => BlockKind::OrderedItem,
+                    _ => BlockKind::BulletItem,
+                };
+                self.open_block(name, kind)
+            }
+            "ul" | "ol" => {
+                self.flush();
+                self.list_stack.push(name);
+            }
+            "br" => {
+                let block = self
+                    .current
+                    .take()
+                    .unwrap_or_else(|| Block::new(self.kind(), vec![]));
+                self.push_block(block);
+            }
+            "b" | "strong" | "i" | "em" | "a" | "span" => {
+                let style = attribute("style").unwrap_or_default().to_ascii_lowercase();
+                let style = style.replace(' ', "");
+
+                self.inline_stack.push(InlineElement {
+                    bold: matches!(name.as_str(), "b" | "strong")
+                        || style.contains("font-weight:bold")
+                        || style.contains("font-weight:700"),
+                    italic: matches!(name.as_str(), "i" | "em")
+                        || style.contains("font-style:italic"),
+                    link: (name == "a")
+                        .then(|| attribute("href").and_then(safe_href))
+                        .flatten(),
+                    name,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "p" | "div" | "blockquote" | "pre" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "li" => {
+                self.flush();
+
+                if let Some(idx) = self.block_stack.iter().rposition(|(open, _)| open == name) {
+                    self.block_stack.truncate(idx);
+                }
+            }
+            "ul" | "ol" => {
+                self.flush();
+
+                if let Some(idx) = self.list_stack.iter().rposition(|open| open == name) {
+                    self.list_stack.truncate(idx);
+                }
+            }
+            _ => {
+                if let Some(idx) = self.inline_stack.iter().rposition(|open| open.name == name) {
+                    self.inline_stack.truncate(idx);
+                }
+            }
+        }
+    }
+
+    fn open_block(&mut self, name: String, kind: BlockKind) {
+        self.flush();
+        self.block_stack.push((name, kind));
+    }
+
+    /// kind of the innermost open block
+    fn kind(&self) -> BlockKind {
+        self.block_stack
+            .last()
+            .map_or(BlockKind::Paragraph, |(_, kind)| *kind)
+    }
+
+    fn style(&self) -> TextStyle {
+        TextStyle {
+            bold: self.inline_stack.iter().any(|open| open.bold),
+            italic: self.inline_stack.iter().any(|open| open.italic),
+            link: self
+                .inline_stack
+                .iter()
+                .rev()
+                .find_map(|open| open.link.clone()),
+        }
+    }
+
+    /// Appends the text with the whitespace collapsed like HTML renders it.
+    fn push_text(&mut self, text: &str) {
+        let decoded = decode_entities(text);
+        let mut collapsed = String::new();
+        let mut ends_with_space = self
+            .current
+            .as_ref()
+            .is_none_or(|block| block.is_empty() || block.text().ends_with(' '));
+        let mut collapsible = self.collapsible_space;
+
+        for ch in decoded.chars() {
+            match ch {
+                ' ' | '\t' | '\n' | '\r' | '\u{c}' => {
+                    if !ends_with_space {
+                        collapsed.push(' ');
+                        (ends_with_space, collapsible) = (true, true);
+                    }
+                }
+                '\u{a0}' => {
+                    collapsed.push(' ');
+                    (ends_with_space, collapsible) = (true, false);
+                }
+                ch => {
+                    collapsed.push(ch);
+                    (ends_with_space, collapsible) = (false, false);
+                }
+            }
+        }
+
+        if collapsed.is_empty() {
+            return;
+        }
+
+        self.collapsible_space = collapsible;
+
+        let style = self.style();
+        let kind = self.kind();
+
+        self.current
+            .get_or_insert_with(|| Block::new(kind, vec![]))
+            .runs
+            .push(TextRun::new(collapsed, style));
+    }
+
+    fn flush(&mut self) {
+        if let Some(block) = self.current.take() {
+            if !block.is_empty() {
+                self.push_block(block);
+            }
+        }
+    }
+
+    fn push_block(&mut self, mut block: Block) {
+        // a collapsed space ending the block is not rendered
+        if std::mem::take(&mut self.collapsible_space) {
+            if let Some(last) = block.runs.last_mut() {
+                last.text.pop();
+            }
+        }
+
+        block.normalize();
+        self.blocks.push(block);
+    }
+
+    fn finish(mut self) -> RichDocument {
+        self.flush();
+
+        RichDocument::new(self.blocks)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rich_text::{
+        document::{Block, BlockKind, RichDocument, TextRun, TextStyle},
+        html::{from_html, to_html},
+    };
+
+    fn styled(text: &str, bold: bool, italic: bool, link: Option<&str>) -> TextRun {
+        TextRun::new(
+            text,
+            TextStyle {
+                bold,
+                italic,
+                link: link.map(String::from),
+            },
+        )
+    }
+
+    #[test]
+    fn it_exports_blocks_and_marks() {
+        let doc = RichDocument::new(vec![
+            Block::new(BlockKind::heading(2), vec![TextRun::plain("Notes")]),
+            Block::new(
+                BlockKind::Paragraph,
+                vec![
+                    TextRun::plain("see "),
+                    styled("the <docs>", true, false, Some("https://x.dev/?a=1&b=2")),
+                ],
+            ),
+            Block::new(BlockKind::BulletItem, vec![TextRun::plain("one")]),
+            Block::new(
+                BlockKind::BulletItem,
+                vec![styled("two", false, true, None)],
+            ),
+            Block::new(BlockKind::OrderedItem, vec![TextRun::plain("first")]),
+            Block::new(BlockKind::Paragraph, vec![]),
+        ]);
+
+        assert_eq!(
+            to_html(&doc),
+            "<h2>Notes</h2>\
+             <p>see <a href=\"https://x.dev/?a=1&amp;b=2\"><strong>the &lt;docs&gt;</strong></a></p>\
+             <ul><li>one</li><li><em>two</em></li></ul>\
+             <ol><li>first</li></ol>\
+             <p><br></p>"
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_spaces_html_would_collapse() {
+        let doc = RichDocument::new(vec![Block::paragraph(" a  b ")]);
+
+        assert_eq!(to_html(&doc), "<p>&nbsp;a &nbsp;b&nbsp;</p>");
+        assert_eq!(from_html(&to_html(&doc)), doc);
+    }
+
+    #[test]
+    fn it_round_trips_the_documents() {
+        let doc = RichDocument::new(vec![
+            Block::new(BlockKind::heading(1), vec![TextRun::plain("Title")]),
+            Block::new(
+                BlockKind::OrderedItem,
+                vec![
+                    styled("bold ", true, false, None),
+                    styled("both", true, true, Some("/path")),
+                ],
+            ),
+            Block::new(BlockKind::Paragraph, vec![]),
+            Block::paragraph("Tom & Jerry"),
+        ]);
+
+        assert_eq!(from_html(&to_html(&doc)), doc);
+    }
+
+    #[test]
+    fn it_sanitizes_pasted_html() {
+        let doc = from_html(
+            "<!DOCTYPE html><html><head><style>p { color: red }</style></head><body>\
+             <h5 onclick=\"steal()\">Deep</h5>\
+             <script>alert('x')</script>\
+             <div>Hello <a href=\"javascript:alert(1)\">world</a><img src=x onerror=alert(1)>\n  again</div>\
+             <p><span style=\"font-weight: 700\">Docs</span> <a href='https://a.dev'>link</a></p>\
+             <table><tr><td>cell</td></tr></table>\
+             </body></html>",
+        );
+
+        assert_eq!(
+            doc,
+            RichDocument::new(vec![
+                Block::new(BlockKind::heading(3), vec![TextRun::plain("Deep")]),
+                Block::paragraph("Hello world again"),
+                Block::new(
+                    BlockKind::Paragraph,
+                    vec![
+                        styled("Docs", true, false, None),
+                        TextRun::plain(" "),
+                        styled("link", false, false, Some("https://a.dev")),
+                    ]
+                ),
+                Block::paragraph("cell"),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_splits_lines_on_breaks() {
+        assert_eq!(
+            from_html("one<br>two<br><br>three &amp; &#x4e2d;&#25991;"),
+            RichDocument::new(vec![
+                Block::paragraph("one"),
+                Block::paragraph("two"),
+                Block::new(BlockKind::Paragraph, vec![]),
+                Block::paragraph("three & 中文"),
+            ])
+        );
+    }
+}
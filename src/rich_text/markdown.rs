@@ -0,0 +1,467 @@
+//! Markdown import and export of the rich text documents.
+//!
+//! Covers what the documents hold: ATX headings, `-` and numbered lists, `**bold**`,
+//! `*italic*` and `[links](https://...)`. Other Markdown is imported as plain text.
+
+use super::document::{safe_href, Block, BlockKind, RichDocument, TextRun, TextStyle};
+
+pub fn to_markdown(document: &RichDocument) -> String {
+    let mut markdown = String::new();
+    let mut number = 0;
+
+    for (idx, block) in document.blocks.iter().enumerate() {
+        let previous = idx
+            .checked_sub(1)
+            .map(|previous| document.blocks[previous].kind);
+
+        // items of a list are on consecutive lines, other blocks are separated by an empty one
+        if let Some(previous) = previous {
+            match block.kind.is_list_item() && previous == block.kind {
+                true => markdown.push('\n'),
+                false => markdown.push_str("\n\n"),
+            }
+        }
+
+        number = match (block.kind, previous) {
+            (BlockKind::OrderedItem, Some(BlockKind::OrderedItem)) => number + 1,
+            _ => 1,
+        };
+
+        let inline = inline_markdown(&block.runs);
+
+        match block.kind {
+            BlockKind::Heading(level) => {
+                markdown.push_str(&format!("{} {inline}", "#".repeat(level as usize)))
+            }
+            BlockKind::BulletItem => markdown.push_str(&format!("- {inline}")),
+            BlockKind::OrderedItem => markdown.push_str(&format!("{number}. {inline}")),
+            BlockKind::Paragraph => markdown.push_str(&escape_block_start(&inline)),
+        }
+    }
+
+    markdown
+}
+
+/// Escapes the start of a paragraph that would read as another block, e.g. `# text`.
+fn escape_block_start(text: &str) -> String {
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+
+    if text.starts_with(['#', '-', '+', '>']) {
+        return format!("\\{text}");
+    }
+
+    match digits > 0 && text[digits..].starts_with(['.', ')']) {
+        true => format!("{}\\{}", &text[..digits], &text[digits..]),
+        false => text.to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '*' | '_' | '[' | ']' | '`') {
+            escaped.push('\\');
+        }
+
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
+fn inline_markdown(runs: &[TextRun]) -> String {
+    let mut markdown = String::new();
+    let mut idx = 0;
+
+    // runs sharing a link are written inside the same brackets
+    while idx < runs.len() {
+        let link = &runs[idx].style.link;
+        let len = runs[idx..]
+            .iter()
+            .take_while(|run| run.style.link == *link)
+            .count();
+        let text: String = runs[idx..idx + len].iter().map(marked_run).collect();
+
+        match link {
+            Some(link) => markdown.push_str(&format!("[{text}]({})", link.replace(' ', "%20"))),
+            None => markdown.push_str(&text),
+        }
+
+        idx += len;
+    }
+
+    markdown
+}
+
+/// Wraps the text in its emphasis markers, keeping the surrounding spaces outside.
+fn marked_run(run: &TextRun) -> String {
+    let marker = match (run.style.bold, run.style.italic) {
+        (true, true) => "***",
+        (true, false) => "**",
+        (false, true) => "*",
+        (false, false) => "",
+    };
+
+    let trimmed = run.text.trim();
+
+    if marker.is_empty() || trimmed.is_empty() {
+        return escape(&run.text);
+    }
+
+    let leading = &run.text[..run.text.len() - run.text.trim_start().len()];
+    let trailing = &run.text[run.text.trim_end().len()..];
+
+    format!("{leading}{marker}{}{marker}{trailing}", escape(trimmed))
+}
+
+pub fn from_markdown(markdown: &str) -> RichDocument {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            let text = paragraph.join(" ");
+
+            blocks.push(Block::new(BlockKind::Paragraph, parse_inline(&text)));
+            paragraph.clear();
+        }
+    };
+
+    for line in markdown.lines() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+            continue;
+        }
+
+        match block_prefix(line) {
+            Some((kind, text)) => {
+                flush(&mut paragraph, &mut blocks);
+                blocks.push(Block::new(kind, parse_inline(text)));
+            }
+            None => paragraph.push(line),
+        }
+    }
+
+    flush(&mut paragraph, &mut blocks);
+
+    RichDocument::new(blocks)
+}
+
+/// Kind of a block starting line with its text.
+fn block_prefix(line: &str) -> Option<(BlockKind, &str)> {
+    let hashes = line.chars().take_while(|ch| *ch == '#').count();
+
+    if (1..=6).contains(&hashes) {
+        let rest = &line[hashes..];
+
+        if rest.is_empty() || rest.starts_with(' ') {
+            return Some((BlockKind::heading(hashes as u8), rest.trim()));
+        }
+    }
+
+    if let Some(rest) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|bullet| line.strip_prefix(bullet))
+    {
+        return Some((BlockKind::BulletItem, rest.trim()));
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    if digits > 0 && digits <= 9 {
+        let rest = &line[digits..];
+
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some((BlockKind::OrderedItem, rest.trim()));
+        }
+    }
+
+    None
+}
+
+/// Parses the emphasis, links and escapes of a block.
+fn parse_inline(text: &str) -> Vec<TextRun> {
+    let mut block = Block::default();
+    InlineParser::new(text).parse(&TextStyle::default(), &mut block.runs);
+    block.normalize();
+
+    block.runs
+}
+
+struct InlineParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl InlineParser {
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn is_word_char(&self, idx: usize) -> bool {
+        self.chars.get(idx).is_some_and(|ch| ch.is_alphanumeric())
+    }
+
+    /// Length of the run of the same char starting at `idx`.
+    fn run_len(&self, idx: usize) -> usize {
+        self.chars[idx..]
+            .iter()
+            .take_while(|ch| **ch == self.chars[idx])
+            .count()
+    }
+
+    fn rest_starts_with(&self, from: usize, pattern: &str) -> bool {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(idx, ch)| self.chars.get(from + idx) == Some(&ch))
+    }
+
+    /// Position of the closing delimiter, skipping escaped chars and longer delimiters.
+    ///
+    /// Like in CommonMark, `_` does not close emphasis within a word.
+    fn find_closing(&self, from: usize, delimiter: &str) -> Option<usize> {
+        let len = delimiter.chars().count();
+        let mut idx = from;
+
+        while idx < self.chars.len() {
+            match self.chars[idx] {
+                '\\' => idx += 2,
+                _ if self.rest_starts_with(idx, delimiter) => {
+                    let run_len = self.run_len(idx);
+                    let intraword = delimiter.starts_with('_') && self.is_word_char(idx + run_len);
+
+                    if run_len == len && !intraword {
+                        return Some(idx);
+                    }
+
+                    idx += run_len;
+                }
+                _ => idx += 1,
+            }
+        }
+
+        None
+    }
+
+    /// Position of the bracket closing the one before `from`, nested brackets included.
+    fn find_bracket(&self, from: usize) -> Option<usize> {
+        let mut depth = 0;
+        let mut idx = from;
+
+        while idx < self.chars.len() {
+            match self.chars[idx] {
+                '\\' => idx += 1,
+                '[' => depth += 1,
+                ']' if depth == 0 => return Some(idx),
+                ']' => depth -= 1,
+                _ => {}
+            }
+
+            idx += 1;
+        }
+
+        None
+    }
+
+    fn parse(&mut self, style: &TextStyle, runs: &mut Vec<TextRun>) {
+        let end = self.chars.len();
+        self.parse_until(end, style, runs);
+    }
+
+    fn parse_until(&mut self, end: usize, style: &TextStyle, runs: &mut Vec<TextRun>) {
+        let mut text = String::new();
+
+        while self.pos < end {
+            let ch = self.chars[self.pos];
+
+            // emphasis, the longest delimiter first, `_` does not open it within a word
+            let delimiter = ["***", "___", "**", "__", "*", "_"]
+                .into_iter()
+                .find(|delimiter| self.rest_starts_with(self.pos, delimiter))
+                .filter(|delimiter| {
+                    !delimiter.starts_with('_') || self.pos == 0 || !self.is_word_char(self.pos - 1)
+                });
+
+            if let Some(delimiter) = delimiter {
+                let start = self.pos + delimiter.chars().count();
+                let closing = self
+                    .find_closing(start, delimiter)
+                    .filter(|closing| *closing > start && *closing <= end);
+
+                if let Some(closing) = closing {
+                    let mut inner = style.clone();
+                    let len = delimiter.chars().count();
+                    inner.bold |= len >= 2;
+                    inner.italic |= len != 2;
+
+                    runs.push(TextRun::new(std::mem::take(&mut text), style.clone()));
+                    self.pos = start;
+                    self.parse_until(closing, &inner, runs);
+                    self.pos = closing + len;
+                    continue;
+                }
+            }
+
+            match ch {
+                '\\' if self.pos + 1 < end => {
+                    text.push(self.chars[self.pos + 1]);
+                    self.pos += 2;
+                }
+                '[' => match self.link_at(self.pos, end) {
+                    Some((text_end, href, after)) => {
+                        let mut inner = style.clone();
+                        inner.link = safe_href(&href);
+
+                        runs.push(TextRun::new(std::mem::take(&mut text), style.clone()));
+                        self.pos += 1;
+                        self.parse_until(text_end, &inner, runs);
+                        self.pos = after;
+                    }
+                    None => {
+                        text.push(ch);
+                        self.pos += 1;
+                    }
+                },
+                ch => {
+                    text.push(ch);
+                    self.pos += 1;
+                }
+            }
+        }
+
+        runs.push(TextRun::new(text, style.clone()));
+    }
+
+    /// End of the text, address and end of a `[text](href)` link starting at `from`.
+    fn link_at(&self, from: usize, end: usize) -> Option<(usize, String, usize)> {
+        let text_end = self
+            .find_bracket(from + 1)
+            .filter(|text_end| *text_end < end)?;
+
+        if self.chars.get(text_end + 1) != Some(&'(') {
+            return None;
+        }
+
+        let href_end = (text_end + 2..end).find(|idx| self.chars[*idx] == ')')?;
+        let href: String = self.chars[text_end + 2..href_end].iter().collect();
+
+        Some((text_end, href.trim().replace("%20", " "), href_end + 1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rich_text::{
+        document::{Block, BlockKind, RichDocument, TextRun, TextStyle},
+        markdown::{from_markdown, to_markdown},
+    };
+
+    fn styled(text: &str, bold: bool, italic: bool, link: Option<&str>) -> TextRun {
+        TextRun::new(
+            text,
+            TextStyle {
+                bold,
+                italic,
+                link: link.map(String::from),
+            },
+        )
+    }
+
+    fn sample() -> RichDocument {
+        RichDocument::new(vec![
+            Block::new(BlockKind::heading(2), vec![TextRun::plain("Release notes")]),
+            Block::new(
+                BlockKind::Paragraph,
+                vec![
+                    TextRun::plain("Read "),
+                    styled("the docs", true, false, Some("https://leptos.dev")),
+                    TextRun::plain(" or "),
+                    styled("not", false, true, None),
+                    TextRun::plain(" (2*3_x)"),
+                ],
+            ),
+            Block::new(
+                BlockKind::BulletItem,
+                vec![styled("fast", true, true, None)],
+            ),
+            Block::new(BlockKind::BulletItem, vec![TextRun::plain("safe")]),
+            Block::new(BlockKind::OrderedItem, vec![TextRun::plain("first")]),
+            Block::new(BlockKind::OrderedItem, vec![TextRun::plain("second")]),
+            Block::paragraph("# not a heading"),
+        ])
+    }
+
+    #[test]
+    fn it_exports_markdown() {
+        assert_eq!(
+            to_markdown(&sample()),
+            "## Release notes\n\n\
+             Read [**the docs**](https://leptos.dev) or *not* (2\\*3\\_x)\n\n\
+             - ***fast***\n\
+             - safe\n\n\
+             1. first\n\
+             2. second\n\n\
+             \\# not a heading"
+        );
+    }
+
+    #[test]
+    fn it_round_trips_markdown() {
+        assert_eq!(from_markdown(&to_markdown(&sample())), sample());
+    }
+
+    #[test]
+    fn it_imports_markdown() {
+        let doc = from_markdown(
+            "# Title\n\nsome __bold__ and\n_italic_ text\n\n* one\n+ two\n3) three\n\n[bad](javascript:alert(1)) **unclosed",
+        );
+
+        assert_eq!(
+            doc,
+            RichDocument::new(vec![
+                Block::new(BlockKind::heading(1), vec![TextRun::plain("Title")]),
+                Block::new(
+                    BlockKind::Paragraph,
+                    vec![
+                        TextRun::plain("some "),
+                        styled("bold", true, false, None),
+                        TextRun::plain(" and "),
+                        styled("italic", false, true, None),
+                        TextRun::plain(" text"),
+                    ]
+                ),
+                Block::new(BlockKind::BulletItem, vec![TextRun::plain("one")]),
+                Block::new(BlockKind::BulletItem, vec![TextRun::plain("two")]),
+                Block::new(BlockKind::OrderedItem, vec![TextRun::plain("three")]),
+                Block::paragraph("bad) **unclosed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_nests_emphasis_and_ignores_intraword_underscores() {
+        assert_eq!(
+            from_markdown("*a **b** c* snake_case_name"),
+            RichDocument::new(vec![Block::new(
+                BlockKind::Paragraph,
+                vec![
+                    styled("a ", false, true, None),
+                    styled("b", true, true, None),
+                    styled(" c", false, true, None),
+                    TextRun::plain(" snake_case_name"),
+                ]
+            )])
+        );
+        assert_eq!(
+            to_markdown(&RichDocument::from_plain_text("2026. A year")),
+            "2026\\. A year"
+        );
+    }
+}
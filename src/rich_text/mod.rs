@@ -0,0 +1,4 @@
+pub mod command;
+pub mod document;
+pub mod html;
+pub mod markdown;
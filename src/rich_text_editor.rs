@@ -0,0 +1,452 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_effect, create_node_ref, create_rw_signal, document,
+    ev::{DragEvent, Event, FocusEvent, KeyboardEvent, MouseEvent},
+    html::Div,
+    view, window, Callable, Callback, CollectView, IntoView, RwSignal, Show, SignalGet,
+    SignalGetUntracked, SignalSet, SignalWith, View,
+};
+use web_sys::{wasm_bindgen::JsCast, ClipboardEvent, Element, Node};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    icons::{
+        bold::Bold, italic::Italic, link::Link, list_bullet::ListBullet,
+        numbered_list::NumberedList,
+    },
+    id::resolve_id,
+    rich_text::{
+        command::{shortcut_command, EditorCommand},
+        document::{
+            safe_href, Block, BlockKind, DocPosition, DocSelection, InlineMark, RichDocument,
+            TextRun, TextStyle,
+        },
+        html::{from_html, to_html},
+    },
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-rich-text-editor flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+const TOOL_STYLE_CLASS: &str =
+    "lumx-rich-text-tool shrink-0 cursor-pointer rounded-md p-1 min-w-7 text-xs font-semibold text-slate-900 hover:bg-slate-200 aria-pressed:bg-blue-100 aria-pressed:text-blue-700";
+
+/// elements holding the blocks of the document, see `from_html`
+const BLOCK_SELECTOR: &str = "p, h1, h2, h3, h4, h5, h6, li, div";
+
+/// `NodeFilter.SHOW_TEXT`
+const SHOW_TEXT: u32 = 4;
+
+/// Content of the editable element, an empty document still gets a line to type in.
+fn editor_html(document: &RichDocument) -> String {
+    match document.blocks.is_empty() {
+        true => String::from("<p><br></p>"),
+        false => to_html(document),
+    }
+}
+
+/// Innermost block elements, in the order of the blocks of the document.
+fn block_elements(root: &Element) -> Vec<Element> {
+    let Ok(nodes) = root.query_selector_all(BLOCK_SELECTOR) else {
+        return vec![];
+    };
+
+    (0..nodes.length())
+        .filter_map(|idx| nodes.item(idx))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .filter(|element| matches!(element.query_selector(BLOCK_SELECTOR), Ok(None)))
+        .collect()
+}
+
+/// Document position of a point of the DOM selection.
+fn dom_to_position(root: &Element, node: &Node, offset: u32) -> DocPosition {
+    let blocks = block_elements(root);
+
+    let Some(block) = blocks.iter().position(|block| block.contains(Some(node))) else {
+        // the point is between the blocks, e.g. after selecting everything
+        return match offset {
+            0 => DocPosition::default(),
+            _ => DocPosition::new(blocks.len().saturating_sub(1), usize::MAX),
+        };
+    };
+
+    let chars = document().create_range().ok().and_then(|range| {
+        range.set_start(&blocks[block], 0).ok()?;
+        range.set_end(node, offset).ok()?;
+
+        Some(String::from(range.to_string()).chars().count())
+    });
+
+    DocPosition::new(block, chars.unwrap_or_default())
+}
+
+/// DOM point of a document position, offsets of text nodes are in UTF-16 code units.
+fn position_to_dom(root: &Element, position: DocPosition) -> (Node, u32) {
+    let blocks = block_elements(root);
+
+    let Some(block) = blocks.get(position.block) else {
+        return (root.clone().into(), root.child_nodes().length());
+    };
+
+    if let Ok(walker) = document().create_tree_walker_with_what_to_show(block, SHOW_TEXT) {
+        let mut remaining = position.offset;
+
+        while let Ok(Some(node)) = walker.next_node() {
+            let text = node.text_content().unwrap_or_default();
+            let len = text.chars().count();
+
+            if remaining <= len {
+                let offset: usize = text.chars().take(remaining).map(char::len_utf16).sum();
+                return (node, offset as u32);
+            }
+
+            remaining -= len;
+        }
+    }
+
+    (block.clone().into(), block.child_nodes().length())
+}
+
+fn read_selection(root: &Element) -> Option<DocSelection> {
+    let selection = window().get_selection().ok()??;
+    let (anchor, focus) = (selection.anchor_node()?, selection.focus_node()?);
+
+    if !root.contains(Some(&anchor)) || !root.contains(Some(&focus)) {
+        return None;
+    }
+
+    Some(DocSelection::new(
+        dom_to_position(root, &anchor, selection.anchor_offset()),
+        dom_to_position(root, &focus, selection.focus_offset()),
+    ))
+}
+
+fn restore_selection(root: &Element, selection: DocSelection) {
+    let (anchor, anchor_offset) = position_to_dom(root, selection.anchor);
+    let (focus, focus_offset) = position_to_dom(root, selection.focus);
+
+    if let Ok(Some(dom_selection)) = window().get_selection() {
+        let _ = dom_selection.set_base_and_extent(&anchor, anchor_offset, &focus, focus_offset);
+    }
+}
+
+/// Asks for the address of the link over the selection, an empty address removes it.
+fn edit_link(document: &mut RichDocument, selection: DocSelection) -> DocSelection {
+    let current = document.link_at(selection.start()).unwrap_or_default();
+
+    let Ok(Some(href)) = window().prompt_with_message_and_default("Link address", &current) else {
+        return selection;
+    };
+
+    let href = href.trim();
+
+    if href.is_empty() {
+        document.set_link(selection, None);
+        return selection;
+    }
+
+    // without a selection the address becomes the text of the link
+    if selection.is_collapsed() {
+        let link = TextRun::new(
+            href,
+            TextStyle {
+                link: safe_href(href),
+                ..TextStyle::default()
+            },
+        );
+        let fragment = RichDocument::new(vec![Block::new(BlockKind::Paragraph, vec![link])]);
+
+        return DocSelection::collapsed(document.insert_fragment(selection.start(), &fragment));
+    }
+
+    document.set_link(selection, Some(href));
+
+    selection
+}
+
+/// Rich text field bound to a document control, see `RichDocument`.
+///
+/// The control is empty while the document holds no text, so the `Required` validator
+/// applies. Pasted and dropped content is sanitized, only the blocks, marks and safe links
+/// of the document model are kept.
+///
+/// Shortcuts: `Ctrl+B` bold, `Ctrl+I` italic, `Ctrl+K` link, `Ctrl+Alt+1` to `3`
+/// headings, `Ctrl+Shift+7` numbered and `Ctrl+Shift+8` bullet lists, `Cmd` on Apple devices.
+#[component]
+pub fn RichTextEditor(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "")] placeholder: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<RichDocument>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "rich-text-editor");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let editor_ref = create_node_ref::<Div>();
+    let control_value = control.value;
+    let selection: RwSignal<Option<DocSelection>> = create_rw_signal(None);
+
+    let commit = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |document: RichDocument| {
+            control_ref.mark_as_dirty();
+            control_ref.set_value((!document.is_empty()).then_some(document));
+        }
+    });
+
+    let sync_selection = move || {
+        if let Some(root) = editor_ref.get_untracked() {
+            selection.set(read_selection(&root));
+        }
+    };
+
+    // applies a change to the document as shown, then renders it and restores the selection
+    let edit = move |update: &dyn Fn(&mut RichDocument, DocSelection) -> DocSelection| {
+        let Some(root) = editor_ref.get_untracked() else {
+            return;
+        };
+
+        let mut document = from_html(&Element::inner_html(&root));
+        let current = read_selection(&root)
+            .or(selection.get_untracked())
+            .unwrap_or_else(|| DocSelection::collapsed(document.end()));
+
+        let updated = update(&mut document, current);
+        document.normalize();
+
+        root.set_inner_html(&editor_html(&document));
+        let _ = root.focus();
+        restore_selection(&root, updated);
+
+        selection.set(Some(updated));
+        commit.call(document);
+    };
+
+    let run_command = move |command: EditorCommand| match command {
+        EditorCommand::Link => edit(&edit_link),
+        command => edit(&|document, selection| {
+            command.apply(document, selection);
+            selection
+        }),
+    };
+
+    let on_input = move |_ev| {
+        if let Some(root) = editor_ref.get_untracked() {
+            commit.call(from_html(&Element::inner_html(&root)));
+        }
+
+        sync_selection();
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        if ev.is_composing() {
+            return;
+        }
+
+        // blocks are split by the model so they stay consistent across browsers
+        if ev.key() == "Enter" {
+            ev.prevent_default();
+            edit(&|document, selection| {
+                let position = document.delete_range(selection);
+
+                DocSelection::collapsed(document.split_block(position))
+            });
+            return;
+        }
+
+        if !(ev.ctrl_key() || ev.meta_key()) {
+            return;
+        }
+
+        if let Some(command) = shortcut_command(&ev.code(), ev.shift_key(), ev.alt_key()) {
+            ev.prevent_default();
+            run_command(command);
+        }
+    };
+
+    // only the sanitized content is inserted
+    let on_paste = move |ev: Event| {
+        let Some(data) = ev
+            .dyn_ref::<ClipboardEvent>()
+            .and_then(ClipboardEvent::clipboard_data)
+        else {
+            return;
+        };
+
+        ev.prevent_default();
+
+        let html = data.get_data("text/html").unwrap_or_default();
+        let fragment = match html.trim().is_empty() {
+            true => RichDocument::from_plain_text(&data.get_data("text/plain").unwrap_or_default()),
+            false => from_html(&html),
+        };
+
+        edit(&|document, selection| {
+            DocSelection::collapsed(document.replace_selection(selection, &fragment))
+        });
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| control_ref.mark_as_touched()
+    };
+
+    // reflect control changes into the element, the content typed by the user is kept
+    // as long as it stands for the same document.
+    create_effect(move |_| {
+        let value = control_value.get().unwrap_or_default();
+
+        if let Some(root) = editor_ref.get() {
+            let shown = from_html(&Element::inner_html(&root));
+
+            if shown != value && !(shown.is_empty() && value.is_empty()) {
+                root.set_inner_html(&editor_html(&value));
+            }
+        }
+    });
+
+    let is_active = move |command: EditorCommand| {
+        selection.get().is_some_and(|selection| {
+            control_value.with(|value| {
+                value
+                    .as_ref()
+                    .is_some_and(|document| command.is_active(document, selection))
+            })
+        })
+    };
+
+    let tool = |command: EditorCommand, name: &'static str, content: View| {
+        view! {
+            <button type="button"
+                tabindex="-1"
+                aria-label=name
+                title=name
+                aria-pressed=move || is_active(command).to_string()
+                aria-controls=form_id.clone()
+                on:mousedown=|ev: MouseEvent| ev.prevent_default()
+                on:click=move |_ev: MouseEvent| run_command(command)
+                class=TOOL_STYLE_CLASS>
+                {content}
+            </button>
+        }
+    };
+
+    let tools = vec![
+        tool(
+            EditorCommand::ToggleMark(InlineMark::Bold),
+            "bold",
+            view! { <Bold class="w-4 h-4" /> }.into_view(),
+        ),
+        tool(
+            EditorCommand::ToggleMark(InlineMark::Italic),
+            "italic",
+            view! { <Italic class="w-4 h-4" /> }.into_view(),
+        ),
+        tool(
+            EditorCommand::ToggleBlock(BlockKind::heading(1)),
+            "heading 1",
+            "H1".into_view(),
+        ),
+        tool(
+            EditorCommand::ToggleBlock(BlockKind::heading(2)),
+            "heading 2",
+            "H2".into_view(),
+        ),
+        tool(
+            EditorCommand::ToggleBlock(BlockKind::heading(3)),
+            "heading 3",
+            "H3".into_view(),
+        ),
+        tool(
+            EditorCommand::ToggleBlock(BlockKind::BulletItem),
+            "bullet list",
+            view! { <ListBullet class="w-4 h-4" /> }.into_view(),
+        ),
+        tool(
+            EditorCommand::ToggleBlock(BlockKind::OrderedItem),
+            "numbered list",
+            view! { <NumberedList class="w-4 h-4" /> }.into_view(),
+        ),
+        tool(
+            EditorCommand::Link,
+            "link",
+            view! { <Link class="w-4 h-4" /> }.into_view(),
+        ),
+    ];
+
+    // field specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the editor while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let focus_editor = move |_ev: MouseEvent| {
+        if let Some(root) = editor_ref.get_untracked() {
+            let _ = root.focus();
+        }
+    };
+
+    view! {
+        <div class=class_props_by_state>
+            <div id=label_id.clone() class="text-xs text-slate-900" on:click=focus_editor>{label}</div>
+
+            <div role="toolbar"
+                aria-label="formatting"
+                class="lumx-rich-text-toolbar flex flex-row flex-wrap items-center gap-x-1 border-b border-slate-200 pb-1.5 mb-1.5">
+                {tools.collect_view()}
+            </div>
+
+            <div class="relative">
+                <Show when=move || control_value.with(Option::is_none)>
+                    <div aria-hidden="true" class="pointer-events-none absolute text-sm text-slate-400">
+                        {placeholder}
+                    </div>
+                </Show>
+
+                <div
+                    node_ref=editor_ref
+                    id=form_id.clone()
+                    contenteditable="true"
+                    role="textbox"
+                    aria-multiline="true"
+                    aria-labelledby=label_id
+                    aria-placeholder=placeholder
+                    aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                    aria-describedby=described_by
+                    class="lumx-rich-text-content min-h-32 outline-none text-sm text-slate-900"
+                    inner_html=editor_html(&control_value.get_untracked().unwrap_or_default())
+                    on:input=on_input
+                    on:keydown=on_keydown
+                    on:keyup=move |_ev: KeyboardEvent| sync_selection()
+                    on:mouseup=move |_ev: MouseEvent| sync_selection()
+                    on:paste=on_paste
+                    on:drop=|ev: DragEvent| ev.prevent_default()
+                    on:blur=on_blur>
+                </div>
+            </div>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </div>
+    }
+}
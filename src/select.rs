@@ -0,0 +1,456 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_effect, create_memo, create_node_ref, create_rw_signal, document,
+    ev::{FocusEvent, KeyboardEvent, MouseEvent},
+    html::Div,
+    store_value, view, Callback, CollectView, IntoView, MaybeSignal, Show, SignalGet,
+    SignalGetUntracked, SignalSet, SignalWith, SignalWithUntracked,
+};
+use leptos_use::on_click_outside;
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    icons::{check::Check, chevron_up_down::ChevronUpDown},
+    id::resolve_id,
+    listbox::{first_enabled, last_enabled, match_typed, move_active, PAGE_SIZE},
+    type_ahead::TypeAheadOption,
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-select rounded-md border box-border bg-white mb-2.5 focus-within:border";
+
+/// keystrokes closer than this, in ms, are matched together against the option labels
+const TYPED_TIMEOUT_MS: f64 = 500.0;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SelectOption<T> {
+    pub value: T,
+    pub label: String,
+    /// consecutive options of the same group are listed under its name
+    pub group: Option<String>,
+    pub disabled: bool,
+}
+
+impl<T> SelectOption<T> {
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            group: None,
+            disabled: false,
+        }
+    }
+
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl From<TypeAheadOption> for SelectOption<TypeAheadOption> {
+    fn from(option: TypeAheadOption) -> Self {
+        let label = option.label.clone();
+
+        Self::new(option, label)
+    }
+}
+
+/// Consecutive runs of options sharing the same group.
+pub(crate) fn group_runs<T>(options: &[SelectOption<T>]) -> Vec<(Option<String>, Vec<usize>)> {
+    let mut runs: Vec<(Option<String>, Vec<usize>)> = Vec::new();
+
+    for (idx, option) in options.iter().enumerate() {
+        match runs.last_mut() {
+            Some((group, indexes)) if *group == option.group => indexes.push(idx),
+            _ => runs.push((option.group.clone(), vec![idx])),
+        }
+    }
+
+    runs
+}
+
+/// Single choice among a fixed list of options, following the WAI-ARIA select-only combobox pattern.
+#[component]
+pub fn Select<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "")] placeholder: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    #[prop(into)] options: MaybeSignal<Vec<SelectOption<T>>>,
+    control: Arc<FormControl<T>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let form_id = resolve_id(id, "select");
+    let label_id = format!("{form_id}-label");
+    let listbox_id = format!("{form_id}-listbox");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let select_ref = create_node_ref::<Div>();
+    let control_value = control.value;
+
+    let options = create_memo(move |_| options.get());
+    let open = create_rw_signal(false);
+    let active = create_rw_signal(None::<usize>);
+    // typed characters and the time of the last keystroke
+    let typed = store_value((String::new(), 0.0));
+
+    let option_id = {
+        let form_id = form_id.clone();
+
+        move |idx: usize| format!("{form_id}-option-{idx}")
+    };
+
+    let selected_idx = create_memo(move |_| {
+        control_value.with(|value| {
+            value.as_ref().and_then(|value| {
+                options.with(|options| options.iter().position(|option| option.value == *value))
+            })
+        })
+    });
+
+    let selected_label = move || {
+        selected_idx.get().and_then(|idx| {
+            options.with(|options| options.get(idx).map(|option| option.label.clone()))
+        })
+    };
+
+    let disabled_flags = move || {
+        options.with_untracked(|options| {
+            options
+                .iter()
+                .map(|option| option.disabled)
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let open_at = move |idx: Option<usize>| {
+        active.set(idx);
+        open.set(true);
+    };
+
+    let close = move || {
+        open.set(false);
+        active.set(None);
+    };
+
+    let select = {
+        let control_ref = Arc::clone(&control);
+
+        move |idx: usize| {
+            let option = options.with_untracked(|options| options.get(idx).cloned());
+
+            if let Some(option) = option.filter(|option| !option.disabled) {
+                control_ref.mark_as_dirty();
+                control_ref.set_value(Some(option.value));
+            }
+
+            close();
+        }
+    };
+
+    // handler to detect clicks outside the element and close the open listbox.
+    let _ = on_click_outside(select_ref, move |_| {
+        if open.get_untracked() {
+            close();
+        }
+    });
+
+    // move to the next option whose label starts with the typed characters
+    let match_keystroke = move |key: &str, time_stamp: f64| {
+        let query = typed.with_value(|(query, last)| match time_stamp - last < TYPED_TIMEOUT_MS {
+            true => format!("{query}{key}"),
+            false => key.to_string(),
+        });
+        typed.set_value((query.clone(), time_stamp));
+
+        let labels = options.with_untracked(|options| {
+            options
+                .iter()
+                .map(|option| option.label.clone())
+                .collect::<Vec<_>>()
+        });
+
+        match_typed(&labels, &disabled_flags(), &query, active.get_untracked())
+    };
+
+    let on_keydown = {
+        let select = select.clone();
+
+        move |ev: KeyboardEvent| {
+            let key = ev.key();
+            let disabled = disabled_flags();
+            let printable =
+                key.chars().count() == 1 && !ev.ctrl_key() && !ev.meta_key() && !ev.alt_key();
+
+            if !open.get_untracked() {
+                match key.as_str() {
+                    "ArrowDown" | "ArrowUp" | "Enter" | " " => {
+                        open_at(selected_idx.get_untracked().or(first_enabled(&disabled)))
+                    }
+                    "Home" => open_at(first_enabled(&disabled)),
+                    "End" => open_at(last_enabled(&disabled)),
+                    _ if printable => {
+                        if let Some(idx) = match_keystroke(&key, ev.time_stamp()) {
+                            open_at(Some(idx));
+                        }
+                    }
+                    _ => return,
+                }
+
+                ev.prevent_default();
+                return;
+            }
+
+            let current = active.get_untracked();
+
+            match key.as_str() {
+                "ArrowUp" if ev.alt_key() => match current {
+                    Some(idx) => select(idx),
+                    None => close(),
+                },
+                "ArrowDown" => active.set(move_active(&disabled, current, 1)),
+                "ArrowUp" => active.set(move_active(&disabled, current, -1)),
+                "PageDown" => active.set(move_active(&disabled, current, PAGE_SIZE as isize)),
+                "PageUp" => active.set(move_active(&disabled, current, -(PAGE_SIZE as isize))),
+                "Home" => active.set(first_enabled(&disabled)),
+                "End" => active.set(last_enabled(&disabled)),
+                "Enter" | " " => match current {
+                    Some(idx) => select(idx),
+                    None => close(),
+                },
+                "Escape" => close(),
+                // select the active option and let the focus move on
+                "Tab" => {
+                    match current {
+                        Some(idx) => select(idx),
+                        None => close(),
+                    }
+                    return;
+                }
+                _ if printable => {
+                    if let Some(idx) = match_keystroke(&key, ev.time_stamp()) {
+                        active.set(Some(idx));
+                    }
+                }
+                _ => return,
+            }
+
+            ev.prevent_default();
+        }
+    };
+
+    let on_trigger_click = move |_ev: MouseEvent| match open.get_untracked() {
+        true => close(),
+        false => open_at(
+            selected_idx
+                .get_untracked()
+                .or(first_enabled(&disabled_flags())),
+        ),
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // keep the active option visible while navigating
+    create_effect({
+        let option_id = option_id.clone();
+
+        move |_| {
+            if let Some(idx) = active.get() {
+                if let Some(element) = document().get_element_by_id(&option_id(idx)) {
+                    element.scroll_into_view_with_bool(false);
+                }
+            }
+        }
+    });
+
+    // select specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the combobox while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let active_descendant = {
+        let option_id = option_id.clone();
+
+        move || active.get().filter(|_| open.get()).map(&option_id)
+    };
+
+    let render_option = {
+        let option_id = option_id.clone();
+
+        move |idx: usize, option: SelectOption<T>| {
+            let select = select.clone();
+            let is_selected = move || selected_idx.get() == Some(idx);
+            let is_active = move || active.get() == Some(idx);
+            let disabled = option.disabled;
+
+            view! {
+                <li id=option_id(idx)
+                    role="option"
+                    aria-selected=move || is_selected().to_string()
+                    aria-disabled=disabled.then_some("true")
+                    class="select-option flex flex-row items-center justify-between text-sm text-slate-900 py-2 px-2"
+                    class=("bg-slate-200", is_active)
+                    class=("cursor-pointer", !disabled)
+                    class=("opacity-50", disabled)
+                    on:mousedown=|ev: MouseEvent| ev.prevent_default()
+                    on:mousemove=move |_ev: MouseEvent| {
+                        if !disabled && !is_active() {
+                            active.set(Some(idx));
+                        }
+                    }
+                    on:click=move |_ev: MouseEvent| {
+                        if !disabled {
+                            select(idx);
+                        }
+                    }>
+                    <span>{option.label}</span>
+                    <Show when=is_selected>
+                        <Check class="w-4 h-4 text-slate-900" />
+                    </Show>
+                </li>
+            }
+        }
+    };
+
+    let render_options = {
+        let form_id = form_id.clone();
+
+        move || {
+            options.with(|options| {
+                group_runs(options)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(run, (group, indexes))| {
+                        let items = indexes
+                            .into_iter()
+                            .map(|idx| render_option(idx, options[idx].clone()))
+                            .collect_view();
+
+                        match group {
+                            Some(group) => {
+                                // runs are numbered, a name can repeat or differ only by case
+                                let group_id = format!("{form_id}-group-{run}");
+
+                                view! {
+                                    <li role="presentation">
+                                        <div id=group_id.clone() class="select-group px-2 pt-2 pb-1 text-xs font-semibold text-slate-500">
+                                            {group}
+                                        </div>
+                                        <ul role="group" aria-labelledby=group_id>
+                                            {items}
+                                        </ul>
+                                    </li>
+                                }
+                                .into_view()
+                            }
+                            None => items,
+                        }
+                    })
+                    .collect_view()
+            })
+        }
+    };
+
+    view! {
+        <div node_ref=select_ref class=class_props_by_state>
+            <div class="lumx-select-relative relative">
+                <div class="select-control flex flex-col p-2.5">
+                    <label id=label_id.clone() for=form_id.clone() class="select-label text-xs text-slate-900">
+                        {label}
+                    </label>
+
+                    <button
+                        type="button"
+                        id=form_id
+                        role="combobox"
+                        aria-haspopup="listbox"
+                        aria-controls=listbox_id.clone()
+                        aria-labelledby=label_id.clone()
+                        aria-expanded=move || open.get().to_string()
+                        aria-activedescendant=active_descendant
+                        aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                        aria-describedby=described_by
+                        on:click=on_trigger_click
+                        on:keydown=on_keydown
+                        on:blur=on_blur
+                        class="select-trigger flex flex-row justify-between items-center gap-x-1 outline-none text-left min-h-5">
+                        {move || match selected_label() {
+                            Some(label) => view! { <span class="select-value text-sm text-slate-900">{label}</span> },
+                            None => view! { <span class="select-placeholder text-sm text-slate-400">{placeholder}</span> },
+                        }}
+                        <ChevronUpDown class="w-4 h-4 text-slate-500" />
+                    </button>
+                </div>
+
+                <Show when=move || open.get()>
+                    <ul id=listbox_id.clone()
+                        role="listbox"
+                        aria-labelledby=label_id.clone()
+                        tabindex="-1"
+                        class="select-overlay-panel w-full absolute mt-1 z-10 max-h-60 overflow-y-auto rounded-md border box-border border-slate-200 bg-white py-1">
+                        {render_options.clone()}
+                    </ul>
+                </Show>
+            </div>
+
+            <div class="px-2.5 pb-1">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::select::{group_runs, SelectOption};
+
+    #[test]
+    fn it_groups_consecutive_options() {
+        let options = vec![
+            SelectOption::new(1, "Draft"),
+            SelectOption::new(2, "Open").with_group("Active"),
+            SelectOption::new(3, "In review").with_group("Active"),
+            SelectOption::new(4, "Done").with_group("Closed"),
+        ];
+
+        assert_eq!(
+            group_runs(&options),
+            vec![
+                (None, vec![0]),
+                (Some(String::from("Active")), vec![1, 2]),
+                (Some(String::from("Closed")), vec![3]),
+            ]
+        );
+    }
+}
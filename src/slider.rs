@@ -0,0 +1,750 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, create_node_ref, create_rw_signal, create_trigger,
+    ev::{FocusEvent, KeyboardEvent, PointerEvent},
+    html::{Div, HtmlElement},
+    use_context, view, watch, Callable, Callback, CollectView, IntoView, MaybeSignal, Show, Signal,
+    SignalGet, SignalGetUntracked, SignalSet, SignalWith, Trigger, View,
+};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    id::resolve_id,
+    layout::Orientation,
+    number::{ratio_value, snap_to_step, step_precision, value_ratio, NumberLocale, NumberValue},
+};
+
+/// steps moved by `PageUp` and `PageDown`
+const PAGE_STEPS: f64 = 10.0;
+
+/// above this number of steps the ticks would be too close to be shown
+const MAX_TICKS: f64 = 100.0;
+
+const THUMB_STYLE_CLASS: &str =
+    "lumx-slider-thumb absolute w-4 h-4 rounded-full border-2 bg-white cursor-grab outline-none focus-visible:ring-2 focus-visible:ring-blue-600/40";
+
+/// Labelled position along the track.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderMark {
+    pub value: f64,
+    pub label: Option<String>,
+}
+
+impl SliderMark {
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// One of the two thumbs of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeThumb {
+    Start,
+    End,
+}
+
+/// Value reached by a slider key, following the WAI-ARIA slider pattern.
+pub fn keyboard_value(key: &str, value: f64, min: f64, max: f64, step: f64) -> Option<f64> {
+    let moved = match key {
+        "ArrowRight" | "ArrowUp" => value + step,
+        "ArrowLeft" | "ArrowDown" => value - step,
+        "PageUp" => value + step * PAGE_STEPS,
+        "PageDown" => value - step * PAGE_STEPS,
+        "Home" => min,
+        "End" => max,
+        _ => return None,
+    };
+
+    Some(snap_to_step(moved, min, max, step))
+}
+
+/// Thumb of the range closest to a value, picked when the track is pressed.
+pub fn closest_thumb(range: (f64, f64), value: f64) -> RangeThumb {
+    match (value - range.0).abs() <= (range.1 - value).abs() {
+        true if value <= range.1 => RangeThumb::Start,
+        _ => RangeThumb::End,
+    }
+}
+
+/// Moves one end of the range, never past the other end.
+///
+/// While both ends overlap the moved thumb is swapped so the range can open in both directions.
+pub fn move_range_end(
+    range: (f64, f64),
+    thumb: RangeThumb,
+    value: f64,
+) -> ((f64, f64), RangeThumb) {
+    let (start, end) = range;
+
+    match thumb {
+        RangeThumb::Start if value > end && start == end => ((start, value), RangeThumb::End),
+        RangeThumb::End if value < start && start == end => ((value, end), RangeThumb::Start),
+        RangeThumb::Start => ((value.min(end), end), thumb),
+        RangeThumb::End => ((start, value.max(start)), thumb),
+    }
+}
+
+/// Position of the pointer along the track, from `0.0` to `1.0`.
+fn pointer_ratio(track: &HtmlElement<Div>, ev: &PointerEvent, orientation: Orientation) -> f64 {
+    let rect = track.get_bounding_client_rect();
+
+    match orientation {
+        Orientation::Horizontal if rect.width() > 0.0 => {
+            (ev.client_x() as f64 - rect.left()) / rect.width()
+        }
+        Orientation::Vertical if rect.height() > 0.0 => {
+            (rect.bottom() - ev.client_y() as f64) / rect.height()
+        }
+        _ => 0.0,
+    }
+}
+
+/// Inline style placing an element at a position along the track.
+fn position_style(orientation: Orientation, ratio: f64) -> String {
+    let percent = ratio * 100.0;
+
+    match orientation {
+        Orientation::Horizontal => format!("left: {percent}%; transform: translateX(-50%);"),
+        Orientation::Vertical => format!("bottom: {percent}%; transform: translateY(50%);"),
+    }
+}
+
+/// Inline style of the filled part of the track between two positions.
+fn fill_style(orientation: Orientation, from: f64, to: f64) -> String {
+    let (from, to) = (from * 100.0, to * 100.0);
+
+    match orientation {
+        Orientation::Horizontal => format!("left: {from}%; width: {}%;", to - from),
+        Orientation::Vertical => format!("bottom: {from}%; height: {}%;", to - from),
+    }
+}
+
+fn layout_classes(orientation: Orientation) -> (&'static str, &'static str) {
+    match orientation {
+        Orientation::Horizontal => (
+            "lumx-slider-track relative w-full h-1.5 my-3 rounded-full bg-slate-200 touch-none cursor-pointer",
+            "lumx-slider-fill absolute h-full rounded-full bg-blue-600",
+        ),
+        Orientation::Vertical => (
+            "lumx-slider-track relative h-48 w-1.5 mx-3 rounded-full bg-slate-200 touch-none cursor-pointer",
+            "lumx-slider-fill absolute w-full rounded-full bg-blue-600",
+        ),
+    }
+}
+
+/// Marks and ticks drawn along the track.
+fn render_marks(
+    marks: &[SliderMark],
+    ticks: bool,
+    min: f64,
+    max: f64,
+    step: f64,
+    orientation: Orientation,
+) -> View {
+    let steps = ((max - min) / step).floor();
+
+    let ticks = match ticks && step > 0.0 && steps <= MAX_TICKS {
+        true => (0..=steps as usize)
+            .map(|idx| {
+                let ratio = value_ratio(min + idx as f64 * step, min, max);
+
+                view! {
+                    <span class="lumx-slider-tick absolute w-0.5 h-0.5 rounded-full bg-slate-400"
+                        style=position_style(orientation, ratio)></span>
+                }
+            })
+            .collect_view(),
+        false => View::default(),
+    };
+
+    let marks = marks
+        .iter()
+        .map(|mark| {
+            let ratio = value_ratio(mark.value, min, max);
+
+            view! {
+                <span class="lumx-slider-mark absolute w-1 h-1 rounded-full bg-slate-500"
+                    style=position_style(orientation, ratio)></span>
+                {mark.label.clone().map(|label| {
+                    let label_class = match orientation {
+                        Orientation::Horizontal => "lumx-slider-mark-label absolute top-3 text-xs text-slate-500 whitespace-nowrap",
+                        Orientation::Vertical => "lumx-slider-mark-label absolute left-3 text-xs text-slate-500 whitespace-nowrap",
+                    };
+
+                    view! {
+                        <span class=label_class style=position_style(orientation, ratio)>{label}</span>
+                    }
+                })}
+            }
+        })
+        .collect_view();
+
+    view! { {ticks} {marks} }.into_view()
+}
+
+#[component]
+fn SliderThumb(
+    /// moves the focus to the thumb when notified
+    focus: Trigger,
+    #[prop(into)] ratio: Signal<f64>,
+    #[prop(into)] value: Signal<f64>,
+    #[prop(into)] value_text: Signal<String>,
+    #[prop(into)] min: MaybeSignal<f64>,
+    #[prop(into)] max: MaybeSignal<f64>,
+    orientation: Orientation,
+    tooltip: bool,
+    #[prop(into)] dragging: Signal<bool>,
+    #[prop(into)] invalid: Signal<bool>,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional, into)] aria_label: Option<String>,
+    #[prop(optional, into)] labelled_by: Option<String>,
+    #[prop(optional, into)] described_by: Option<Signal<Option<String>>>,
+    on_keydown: Callback<KeyboardEvent>,
+    on_blur: Callback<FocusEvent>,
+) -> impl IntoView {
+    let thumb_ref = create_node_ref::<Div>();
+    let focused = create_rw_signal(false);
+
+    let _ = watch(
+        move || focus.track(),
+        move |_, _, _| {
+            if let Some(thumb) = thumb_ref.get_untracked() {
+                let _ = thumb.focus();
+            }
+        },
+        false,
+    );
+    let (min, max) = (
+        Signal::derive(move || min.get()),
+        Signal::derive(move || max.get()),
+    );
+
+    let thumb_class = move || match invalid.get() {
+        true => format!("{THUMB_STYLE_CLASS} lumx-invalid border-red-600"),
+        false => format!("{THUMB_STYLE_CLASS} border-blue-600"),
+    };
+
+    let tooltip_class = match orientation {
+        Orientation::Horizontal => "lumx-slider-tooltip absolute bottom-6 left-1/2 -translate-x-1/2 rounded-md bg-slate-900 px-1.5 py-0.5 text-xs text-white whitespace-nowrap",
+        Orientation::Vertical => "lumx-slider-tooltip absolute left-6 top-1/2 -translate-y-1/2 rounded-md bg-slate-900 px-1.5 py-0.5 text-xs text-white whitespace-nowrap",
+    };
+
+    view! {
+        <div node_ref=thumb_ref
+            id=id
+            role="slider"
+            tabindex="0"
+            aria-label=aria_label
+            aria-labelledby=labelled_by
+            aria-describedby=move || described_by.and_then(|described_by| described_by.get())
+            aria-orientation=orientation.as_str()
+            aria-valuemin=move || min.get()
+            aria-valuemax=move || max.get()
+            aria-valuenow=move || value.get()
+            aria-valuetext=move || value_text.get()
+            aria-invalid=move || invalid.get().to_string()
+            class=thumb_class
+            style=move || position_style(orientation, ratio.get())
+            on:keydown=move |ev: KeyboardEvent| on_keydown.call(ev)
+            on:focus=move |_ev: FocusEvent| focused.set(true)
+            on:blur=move |ev: FocusEvent| {
+                focused.set(false);
+                on_blur.call(ev);
+            }>
+            <Show when=move || tooltip && (focused.get() || dragging.get())>
+                <span class=tooltip_class>{move || value_text.get()}</span>
+            </Show>
+        </div>
+    }
+}
+
+/// Numeric value picked along a track, bound to an integer or decimal control.
+#[component]
+pub fn Slider<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(default = 0.0)] min: f64,
+    #[prop(default = 100.0)] max: f64,
+    #[prop(default = 1.0)] step: f64,
+    /// labelled positions along the track
+    #[prop(optional)]
+    marks: Vec<SliderMark>,
+    /// draws a tick at every step
+    #[prop(optional)]
+    ticks: bool,
+    /// shows the value above the thumb while it is focused or dragged
+    #[prop(optional)]
+    tooltip: bool,
+    #[prop(optional)] orientation: Orientation,
+    /// separators, defaults to a provided `NumberLocale` or to `NumberLocale::en`
+    #[prop(optional)]
+    locale: Option<NumberLocale>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<T>>,
+) -> impl IntoView
+where
+    T: NumberValue,
+{
+    let form_id = resolve_id(id, "slider");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let track_ref = create_node_ref::<Div>();
+    let focus_thumb = create_trigger();
+    let control_value = control.value;
+    let dragging = create_rw_signal(false);
+
+    let locale = locale
+        .or_else(use_context::<NumberLocale>)
+        .unwrap_or_default();
+    let precision = match T::INTEGER {
+        true => 0,
+        false => step_precision(step),
+    };
+
+    // an empty control shows the thumb at the start of the track
+    let value = Signal::derive(move || control_value.get().map_or(min, T::to_f64));
+    let ratio = Signal::derive(move || value_ratio(value.get(), min, max));
+    let value_text = Signal::derive(move || locale.format(value.get(), Some(precision)));
+    let invalid = Signal::derive(move || visible_err.with(Option::is_some));
+
+    let set_value = {
+        let control_ref = Arc::clone(&control);
+
+        move |next: f64| {
+            let next = T::from_f64(next);
+
+            if control_value.get_untracked() != Some(next) {
+                control_ref.mark_as_dirty();
+                control_ref.set_value(Some(next));
+            }
+        }
+    };
+
+    let on_keydown = Callback::new({
+        let set_value = set_value.clone();
+
+        move |ev: KeyboardEvent| {
+            let current = control_value.get_untracked().map_or(min, T::to_f64);
+
+            if let Some(next) = keyboard_value(&ev.key(), current, min, max, step) {
+                ev.prevent_default();
+                set_value(next);
+            }
+        }
+    });
+
+    let on_blur = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| control_ref.mark_as_touched()
+    });
+
+    let on_pointerdown = {
+        let set_value = set_value.clone();
+
+        move |ev: PointerEvent| {
+            let Some(track) = track_ref.get_untracked() else {
+                return;
+            };
+
+            // keep the focus on the thumb rather than on the pressed track
+            ev.prevent_default();
+            let _ = track.set_pointer_capture(ev.pointer_id());
+
+            focus_thumb.notify();
+            dragging.set(true);
+            set_value(ratio_value(
+                pointer_ratio(&track, &ev, orientation),
+                min,
+                max,
+                step,
+            ));
+        }
+    };
+
+    let on_pointermove = move |ev: PointerEvent| {
+        if !dragging.get_untracked() {
+            return;
+        }
+
+        if let Some(track) = track_ref.get_untracked() {
+            set_value(ratio_value(
+                pointer_ratio(&track, &ev, orientation),
+                min,
+                max,
+                step,
+            ));
+        }
+    };
+
+    let on_pointerup = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: PointerEvent| {
+            if dragging.get_untracked() {
+                dragging.set(false);
+                control_ref.mark_as_touched();
+            }
+        }
+    };
+
+    // the feedback region only describes the slider while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        Signal::derive(move || {
+            (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+        })
+    };
+
+    let (track_class, fill_class) = layout_classes(orientation);
+    let container_class = match orientation {
+        Orientation::Horizontal => "lumx-slider flex flex-col mb-2.5",
+        Orientation::Vertical => "lumx-slider inline-flex flex-col items-center mb-2.5",
+    };
+
+    view! {
+        <div class=container_class>
+            <div class="flex flex-row justify-between text-xs text-slate-900">
+                <span id=label_id.clone()>{label}</span>
+                <Show when=move || !tooltip>
+                    <span class="lumx-slider-value text-slate-500">{move || value_text.get()}</span>
+                </Show>
+            </div>
+
+            <div node_ref=track_ref
+                class=track_class
+                on:pointerdown=on_pointerdown
+                on:pointermove=on_pointermove
+                on:pointerup=on_pointerup.clone()
+                on:pointercancel=on_pointerup>
+                <div class=fill_class style=move || fill_style(orientation, 0.0, ratio.get())></div>
+
+                {render_marks(&marks, ticks, min, max, step, orientation)}
+
+                <SliderThumb
+                    focus=focus_thumb
+                    id=form_id
+                    labelled_by=label_id
+                    described_by=described_by
+                    ratio=ratio
+                    value=value
+                    value_text=value_text
+                    min=min
+                    max=max
+                    orientation=orientation
+                    tooltip=tooltip
+                    dragging=dragging
+                    invalid=invalid
+                    on_keydown=on_keydown
+                    on_blur=on_blur />
+            </div>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </div>
+    }
+}
+
+/// Range of numeric values picked with two thumbs, bound to a `(start, end)` control.
+#[component]
+pub fn RangeSlider<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(default = 0.0)] min: f64,
+    #[prop(default = 100.0)] max: f64,
+    #[prop(default = 1.0)] step: f64,
+    /// labelled positions along the track
+    #[prop(optional)]
+    marks: Vec<SliderMark>,
+    /// draws a tick at every step
+    #[prop(optional)]
+    ticks: bool,
+    /// shows the values above the thumbs while they are focused or dragged
+    #[prop(optional)]
+    tooltip: bool,
+    #[prop(optional)] orientation: Orientation,
+    /// separators, defaults to a provided `NumberLocale` or to `NumberLocale::en`
+    #[prop(optional)]
+    locale: Option<NumberLocale>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<(T, T)>>,
+) -> impl IntoView
+where
+    T: NumberValue,
+{
+    let form_id = resolve_id(id, "range-slider");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let track_ref = create_node_ref::<Div>();
+    let focus_start = create_trigger();
+    let focus_end = create_trigger();
+    let control_value = control.value;
+    let dragging = create_rw_signal(None::<RangeThumb>);
+
+    let locale = locale
+        .or_else(use_context::<NumberLocale>)
+        .unwrap_or_default();
+    let precision = match T::INTEGER {
+        true => 0,
+        false => step_precision(step),
+    };
+
+    // an empty control covers the whole track
+    let range = move || {
+        control_value
+            .get()
+            .map_or((min, max), |(start, end)| (start.to_f64(), end.to_f64()))
+    };
+    let range_untracked = move || {
+        control_value
+            .get_untracked()
+            .map_or((min, max), |(start, end)| (start.to_f64(), end.to_f64()))
+    };
+
+    let start = Signal::derive(move || range().0);
+    let end = Signal::derive(move || range().1);
+    let start_ratio = Signal::derive(move || value_ratio(start.get(), min, max));
+    let end_ratio = Signal::derive(move || value_ratio(end.get(), min, max));
+    let start_text = Signal::derive(move || locale.format(start.get(), Some(precision)));
+    let end_text = Signal::derive(move || locale.format(end.get(), Some(precision)));
+    let invalid = Signal::derive(move || visible_err.with(Option::is_some));
+
+    // moves one end, returning the thumb that actually moved
+    let move_end = {
+        let control_ref = Arc::clone(&control);
+
+        move |thumb: RangeThumb, value: f64| {
+            let ((start, end), thumb) = move_range_end(range_untracked(), thumb, value);
+            let next = (T::from_f64(start), T::from_f64(end));
+
+            if control_value.get_untracked() != Some(next) {
+                control_ref.mark_as_dirty();
+                control_ref.set_value(Some(next));
+            }
+
+            thumb
+        }
+    };
+
+    let focus_thumb = move |thumb: RangeThumb| match thumb {
+        RangeThumb::Start => focus_start.notify(),
+        RangeThumb::End => focus_end.notify(),
+    };
+
+    let keydown_for = {
+        let move_end = move_end.clone();
+
+        move |thumb: RangeThumb| {
+            let move_end = move_end.clone();
+
+            Callback::new(move |ev: KeyboardEvent| {
+                let (start, end) = range_untracked();
+                let current = match thumb {
+                    RangeThumb::Start => start,
+                    RangeThumb::End => end,
+                };
+
+                if let Some(next) = keyboard_value(&ev.key(), current, min, max, step) {
+                    ev.prevent_default();
+                    move_end(thumb, next);
+                }
+            })
+        }
+    };
+
+    let on_blur = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| control_ref.mark_as_touched()
+    });
+
+    let on_pointerdown = {
+        let move_end = move_end.clone();
+
+        move |ev: PointerEvent| {
+            let Some(track) = track_ref.get_untracked() else {
+                return;
+            };
+
+            // keep the focus on the moved thumb rather than on the pressed track
+            ev.prevent_default();
+            let _ = track.set_pointer_capture(ev.pointer_id());
+
+            let value = ratio_value(pointer_ratio(&track, &ev, orientation), min, max, step);
+            let thumb = move_end(closest_thumb(range_untracked(), value), value);
+
+            focus_thumb(thumb);
+
+            dragging.set(Some(thumb));
+        }
+    };
+
+    let on_pointermove = move |ev: PointerEvent| {
+        let Some(thumb) = dragging.get_untracked() else {
+            return;
+        };
+
+        if let Some(track) = track_ref.get_untracked() {
+            let value = ratio_value(pointer_ratio(&track, &ev, orientation), min, max, step);
+            let moved = move_end(thumb, value);
+
+            if moved != thumb {
+                focus_thumb(moved);
+
+                dragging.set(Some(moved));
+            }
+        }
+    };
+
+    let on_pointerup = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: PointerEvent| {
+            if dragging.get_untracked().is_some() {
+                dragging.set(None);
+                control_ref.mark_as_touched();
+            }
+        }
+    };
+
+    // the feedback region only describes the slider while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        Signal::derive(move || {
+            (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+        })
+    };
+
+    let (track_class, fill_class) = layout_classes(orientation);
+    let container_class = match orientation {
+        Orientation::Horizontal => "lumx-slider lumx-range-slider flex flex-col mb-2.5",
+        Orientation::Vertical => {
+            "lumx-slider lumx-range-slider inline-flex flex-col items-center mb-2.5"
+        }
+    };
+
+    view! {
+        <div id=form_id role="group" aria-labelledby=label_id.clone() class=container_class>
+            <div class="flex flex-row justify-between text-xs text-slate-900">
+                <span id=label_id>{label}</span>
+                <Show when=move || !tooltip>
+                    <span class="lumx-slider-value text-slate-500">
+                        {move || format!("{} – {}", start_text.get(), end_text.get())}
+                    </span>
+                </Show>
+            </div>
+
+            <div node_ref=track_ref
+                class=track_class
+                on:pointerdown=on_pointerdown
+                on:pointermove=on_pointermove
+                on:pointerup=on_pointerup.clone()
+                on:pointercancel=on_pointerup>
+                <div class=fill_class
+                    style=move || fill_style(orientation, start_ratio.get(), end_ratio.get())></div>
+
+                {render_marks(&marks, ticks, min, max, step, orientation)}
+
+                <SliderThumb
+                    focus=focus_start
+                    aria_label="minimum"
+                    described_by=described_by
+                    ratio=start_ratio
+                    value=start
+                    value_text=start_text
+                    min=min
+                    max=end
+                    orientation=orientation
+                    tooltip=tooltip
+                    dragging=Signal::derive(move || dragging.get() == Some(RangeThumb::Start))
+                    invalid=invalid
+                    on_keydown=keydown_for.clone()(RangeThumb::Start)
+                    on_blur=on_blur />
+
+                <SliderThumb
+                    focus=focus_end
+                    aria_label="maximum"
+                    described_by=described_by
+                    ratio=end_ratio
+                    value=end
+                    value_text=end_text
+                    min=start
+                    max=max
+                    orientation=orientation
+                    tooltip=tooltip
+                    dragging=Signal::derive(move || dragging.get() == Some(RangeThumb::End))
+                    invalid=invalid
+                    on_keydown=keydown_for(RangeThumb::End)
+                    on_blur=on_blur />
+            </div>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::slider::{closest_thumb, keyboard_value, move_range_end, RangeThumb};
+
+    #[test]
+    fn it_moves_with_the_keyboard() {
+        assert_eq!(
+            keyboard_value("ArrowRight", 10.0, 0.0, 100.0, 5.0),
+            Some(15.0)
+        );
+        assert_eq!(keyboard_value("ArrowDown", 0.0, 0.0, 100.0, 5.0), Some(0.0));
+        assert_eq!(keyboard_value("PageUp", 60.0, 0.0, 100.0, 5.0), Some(100.0));
+        assert_eq!(keyboard_value("Home", 60.0, 0.0, 100.0, 5.0), Some(0.0));
+        assert_eq!(keyboard_value("End", 60.0, 0.0, 100.0, 5.0), Some(100.0));
+        assert_eq!(keyboard_value("a", 60.0, 0.0, 100.0, 5.0), None);
+    }
+
+    #[test]
+    fn it_picks_the_closest_thumb() {
+        assert_eq!(closest_thumb((20.0, 80.0), 10.0), RangeThumb::Start);
+        assert_eq!(closest_thumb((20.0, 80.0), 45.0), RangeThumb::Start);
+        assert_eq!(closest_thumb((20.0, 80.0), 60.0), RangeThumb::End);
+        assert_eq!(closest_thumb((50.0, 50.0), 70.0), RangeThumb::End);
+        assert_eq!(closest_thumb((50.0, 50.0), 30.0), RangeThumb::Start);
+    }
+
+    #[test]
+    fn it_keeps_the_range_ordered() {
+        assert_eq!(
+            move_range_end((20.0, 80.0), RangeThumb::Start, 90.0),
+            ((80.0, 80.0), RangeThumb::Start)
+        );
+        assert_eq!(
+            move_range_end((20.0, 80.0), RangeThumb::End, 10.0),
+            ((20.0, 20.0), RangeThumb::End)
+        );
+        assert_eq!(
+            move_range_end((50.0, 50.0), RangeThumb::Start, 60.0),
+            ((50.0, 60.0), RangeThumb::End)
+        );
+        assert_eq!(
+            move_range_end((50.0, 50.0), RangeThumb::End, 40.0),
+            ((40.0, 50.0), RangeThumb::Start)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use leptos::{
+    component, ev::MouseEvent, view, Callback, IntoView, SignalGet, SignalGetUntracked, SignalWith,
+};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    id::resolve_id,
+};
+
+const TRACK_STYLE_CLASS: &str =
+    "lumx-switch-track relative inline-flex shrink-0 w-9 h-5 rounded-full border cursor-pointer transition-colors outline-none focus-visible:ring-2 focus-visible:ring-blue-600/40";
+
+const THUMB_STYLE_CLASS: &str =
+    "lumx-switch-thumb pointer-events-none absolute top-0.5 w-3.5 h-3.5 rounded-full bg-white shadow transition-transform";
+
+/// On and off toggle bound to a boolean control, an empty control is off.
+#[component]
+pub fn Switch(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<bool>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "switch");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let control_value = control.value;
+
+    let checked = move || control_value.get().unwrap_or_default();
+
+    let on_click = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: MouseEvent| {
+            let checked = control_value.get_untracked().unwrap_or_default();
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value(Some(!checked));
+        }
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // switch specific properties by state
+    let track_class = move || match (checked(), visible_err.with(Option::is_some)) {
+        (true, _) => format!("{TRACK_STYLE_CLASS} bg-blue-600 border-blue-600"),
+        (false, true) => format!("{TRACK_STYLE_CLASS} lumx-invalid bg-slate-200 border-red-600"),
+        (false, false) => format!("{TRACK_STYLE_CLASS} bg-slate-200 border-slate-200"),
+    };
+
+    let thumb_class = move || match checked() {
+        true => format!("{THUMB_STYLE_CLASS} translate-x-4 left-0.5"),
+        false => format!("{THUMB_STYLE_CLASS} left-0.5"),
+    };
+
+    // the feedback region only describes the switch while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    view! {
+        <div class="lumx-switch flex flex-col mb-2.5">
+            <div class="inline-flex items-center gap-x-2">
+                <button
+                    type="button"
+                    role="switch"
+                    id=form_id.clone()
+                    class=track_class
+                    aria-checked=move || checked().to_string()
+                    aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                    aria-describedby=described_by
+                    on:click=on_click
+                    on:blur=on_blur>
+                    <span class=thumb_class></span>
+                </button>
+
+                <label for=form_id class="lumx-switch-label text-sm text-slate-900 cursor-pointer">
+                    {label}
+                </label>
+            </div>
+
+            <div class="pl-11">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,461 @@
+use std::{rc::Rc, sync::Arc};
+
+use leptos::{
+    component, create_memo, create_node_ref, create_rw_signal,
+    ev::{Event, FocusEvent, KeyboardEvent, MouseEvent},
+    event_target_value,
+    html::Input,
+    view, Callable, Callback, CollectView, IntoView, Resource, SignalGet, SignalGetUntracked,
+    SignalSet, SignalWith, SignalWithUntracked, Suspense, WriteSignal,
+};
+use leptos_use::watch_debounced;
+use web_sys::{wasm_bindgen::JsCast, ClipboardEvent};
+
+use crate::{
+    chip::Chip,
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ControlValidator, ValidatorKey, ValidatorMetadata},
+    },
+    id::resolve_id,
+    listbox::move_active,
+    type_ahead::TypeAheadOption,
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-tag-input rounded-md border box-border bg-white mb-2.5 focus-within:border";
+
+/// characters ending a tag while typing or pasting
+pub const TAG_SEPARATORS: [char; 4] = [',', ';', '\n', '\t'];
+
+/// Tags of a typed or pasted text, trimmed and without the empty ones.
+pub fn split_tags(text: &str) -> Vec<String> {
+    text.split(TAG_SEPARATORS)
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn same_tag(tag: &str, other: &str, case_sensitive: bool) -> bool {
+    match case_sensitive {
+        true => tag == other,
+        false => tag.to_lowercase() == other.to_lowercase(),
+    }
+}
+
+/// Appends the candidates that are not there yet, up to `max_tags`.
+pub fn add_tags<I>(
+    tags: &[String],
+    candidates: I,
+    max_tags: Option<usize>,
+    case_sensitive: bool,
+) -> Vec<String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut tags = tags.to_vec();
+
+    for candidate in candidates {
+        if max_tags.is_some_and(|max| tags.len() >= max) {
+            break;
+        }
+
+        if !tags
+            .iter()
+            .any(|tag| same_tag(tag, &candidate, case_sensitive))
+        {
+            tags.push(candidate);
+        }
+    }
+
+    tags
+}
+
+/// Free form list of values entered as chips, a value ends with `Enter` or a comma.
+///
+/// Tags failing `tag_validators` are kept and marked as invalid, add `EachItem`
+/// with the same validators to the control so it becomes invalid as well.
+/// Suggestions are fetched as for `TypeAhead`, `changes` receiving the typed text.
+#[component]
+pub fn TagInput(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "")] placeholder: &'static str,
+    #[prop(default = 800.0)] debounced_ms: f64,
+    #[prop(optional, into)] id: Option<String>,
+    /// no more tags can be added once reached
+    #[prop(optional)]
+    max_tags: Option<usize>,
+    /// tell apart tags differing only by case, e.g. `Rust` and `rust`
+    #[prop(optional)]
+    case_sensitive: bool,
+    /// validators of a single tag, marking the chips of the invalid ones
+    #[prop(optional)]
+    tag_validators: Vec<Box<dyn ControlValidator<String>>>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    #[prop(optional)] suggestions: Option<Resource<String, Vec<TypeAheadOption>>>,
+    #[prop(optional)] changes: Option<WriteSignal<String>>,
+    control: Arc<FormControl<Vec<String>>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "tag-input");
+    let label_id = format!("{form_id}-label");
+    let listbox_id = format!("{form_id}-listbox");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let input_ref = create_node_ref::<Input>();
+    let control_value = control.value;
+
+    let draft = create_rw_signal(String::new());
+    let open = create_rw_signal(false);
+    let active = create_rw_signal(None::<usize>);
+    let tag_validators = Rc::new(tag_validators);
+
+    let tags = move || control_value.get().unwrap_or_default();
+
+    let limit_reached = move || {
+        control_value
+            .with(|value| max_tags.is_some_and(|max| value.as_ref().map_or(0, Vec::len) >= max))
+    };
+
+    if let Some(changes) = changes {
+        let _ = watch_debounced(
+            move || draft.get(),
+            move |draft, _, _| changes.set(draft.trim().to_string()),
+            debounced_ms,
+        );
+    }
+
+    // suggestions not picked yet
+    let options = create_memo(move |_| {
+        let options = suggestions
+            .and_then(|suggestions| suggestions.get())
+            .unwrap_or_default();
+
+        control_value.with(|value| {
+            let tags = value.as_deref().unwrap_or_default();
+
+            options
+                .into_iter()
+                .filter(|option| {
+                    !tags
+                        .iter()
+                        .any(|tag| same_tag(tag, &option.value, case_sensitive))
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let expanded = move || {
+        open.get()
+            && draft.with(|draft| !draft.trim().is_empty())
+            && options.with(|o| !o.is_empty())
+    };
+
+    let option_id = {
+        let form_id = form_id.clone();
+
+        move |idx: usize| format!("{form_id}-option-{idx}")
+    };
+
+    let add = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |candidates: Vec<String>| {
+            let current = control_value.get_untracked().unwrap_or_default();
+            let updated = add_tags(&current, candidates, max_tags, case_sensitive);
+
+            if updated.len() != current.len() {
+                control_ref.mark_as_dirty();
+                control_ref.set_value(Some(updated));
+            }
+        }
+    });
+
+    let remove = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |index: usize| {
+            let mut updated = control_value.get_untracked().unwrap_or_default();
+
+            if index < updated.len() {
+                updated.remove(index);
+                control_ref.mark_as_dirty();
+                control_ref.set_value((!updated.is_empty()).then_some(updated));
+            }
+        }
+    });
+
+    let commit_draft = move || {
+        let typed = draft.get_untracked();
+
+        if !typed.trim().is_empty() {
+            add.call(split_tags(&typed));
+        }
+
+        draft.set(String::new());
+        active.set(None);
+    };
+
+    let pick = move |idx: usize| {
+        if let Some(option) = options.with_untracked(|options| options.get(idx).cloned()) {
+            add.call(vec![option.value]);
+            draft.set(String::new());
+            active.set(None);
+        }
+
+        if let Some(input) = input_ref.get_untracked() {
+            let _ = input.focus();
+        }
+    };
+
+    let on_input = move |ev: Event| {
+        let value = event_target_value(&ev);
+
+        // a separator typed or entered by the virtual keyboard ends the preceding tags
+        match value.rfind(TAG_SEPARATORS) {
+            Some(end) => {
+                add.call(split_tags(&value[..end]));
+                draft.set(value[end + 1..].to_string());
+            }
+            None => draft.set(value),
+        }
+
+        active.set(None);
+        open.set(true);
+    };
+
+    let on_paste = move |ev: Event| {
+        let pasted = ev
+            .dyn_ref::<ClipboardEvent>()
+            .and_then(ClipboardEvent::clipboard_data)
+            .and_then(|data| data.get_data("text/plain").ok())
+            .filter(|pasted| pasted.contains(TAG_SEPARATORS));
+
+        // a single value is pasted into the draft as typed
+        if let Some(pasted) = pasted {
+            ev.prevent_default();
+            add.call(split_tags(&format!("{}{pasted}", draft.get_untracked())));
+            draft.set(String::new());
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let len = options.with_untracked(Vec::len);
+        let disabled = vec![false; len];
+        let current = active.get_untracked().filter(|_| expanded());
+
+        match ev.key().as_str() {
+            "ArrowDown" if len > 0 => {
+                open.set(true);
+                active.set(move_active(&disabled, current, 1));
+            }
+            "ArrowUp" if len > 0 => {
+                open.set(true);
+                active.set(move_active(&disabled, current, -1));
+            }
+            "Enter" => match current {
+                Some(idx) => pick(idx),
+                // an empty field lets the form be submitted
+                None if draft.with_untracked(|draft| draft.trim().is_empty()) => return,
+                None => commit_draft(),
+            },
+            "," | ";" => commit_draft(),
+            "Escape" if expanded() => {
+                open.set(false);
+                active.set(None);
+            }
+            // removes the last chip
+            "Backspace" if draft.with_untracked(String::is_empty) => {
+                let count =
+                    control_value.with_untracked(|value| value.as_ref().map_or(0, Vec::len));
+
+                match count.checked_sub(1) {
+                    Some(last) => remove.call(last),
+                    None => return,
+                }
+            }
+            _ => return,
+        }
+
+        ev.prevent_default();
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| {
+            commit_draft();
+            open.set(false);
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // focus the input when clicking around the chips
+    let on_field_click = move |_ev: MouseEvent| {
+        if let Some(input) = input_ref.get_untracked() {
+            let _ = input.focus();
+        }
+    };
+
+    // tag input specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the input while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let active_descendant = {
+        let option_id = option_id.clone();
+
+        move || active.get().filter(|_| expanded()).map(&option_id)
+    };
+
+    let render_chips = move || {
+        tags()
+            .into_iter()
+            .enumerate()
+            .map(|(index, tag)| {
+                let invalid = tag_validators
+                    .iter()
+                    .any(|validator| validator.validate(Some(tag.clone())).is_err());
+
+                view! {
+                    <Chip label=tag invalid=invalid on_remove=move |_| remove.call(index) />
+                }
+            })
+            .collect_view()
+    };
+
+    let render_options = move || {
+        options
+            .get()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, option)| {
+                let is_active = move || active.get() == Some(idx);
+
+                view! {
+                    <li id=option_id(idx)
+                        role="option"
+                        aria-selected=move || is_active().to_string()
+                        class="tag-input-option text-sm text-slate-900 py-2 px-2 cursor-pointer"
+                        class=("bg-slate-200", is_active)
+                        on:mousedown=|ev: MouseEvent| ev.prevent_default()
+                        on:mousemove=move |_ev: MouseEvent| {
+                            if !is_active() {
+                                active.set(Some(idx));
+                            }
+                        }
+                        on:click=move |_ev: MouseEvent| pick(idx)>
+                        {option.label}
+                    </li>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class=class_props_by_state>
+            <div class="lumx-tag-input-relative relative">
+                <div class="tag-input-control flex flex-col p-2.5" on:click=on_field_click>
+                    <label id=label_id.clone() for=form_id.clone() class="tag-input-label text-xs text-slate-900">
+                        {label}
+                    </label>
+
+                    <div class="tag-input-chips flex flex-row flex-wrap items-center gap-1 min-h-5">
+                        {render_chips}
+
+                        <input node_ref=input_ref
+                            id=form_id
+                            type="text"
+                            class="grow min-w-16 outline-none text-sm bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
+                            placeholder=move || control_value.with(Option::is_none).then_some(placeholder)
+                            autocomplete="off"
+                            readonly=limit_reached
+                            role=suggestions.map(|_| "combobox")
+                            aria-autocomplete=suggestions.map(|_| "list")
+                            aria-controls=suggestions.map(|_| listbox_id.clone())
+                            aria-expanded=move || suggestions.map(|_| expanded().to_string())
+                            aria-activedescendant=active_descendant
+                            aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                            aria-describedby=described_by
+                            prop:value=move || draft.get()
+                            on:input=on_input
+                            on:paste=on_paste
+                            on:keydown=on_keydown
+                            on:blur=on_blur />
+                    </div>
+                </div>
+
+                {suggestions.map(|_| view! {
+                    <ul id=listbox_id.clone()
+                        role="listbox"
+                        aria-labelledby=label_id.clone()
+                        tabindex="-1"
+                        class="tag-input-overlay-panel w-full absolute mt-1 z-10 max-h-60 overflow-y-auto rounded-md border box-border border-slate-200 bg-white py-1"
+                        class=("hidden", move || !expanded())>
+                        <Suspense>
+                            {render_options.clone()}
+                        </Suspense>
+                    </ul>
+                })}
+            </div>
+
+            <div class="px-2.5 pb-1">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::tag_input::{add_tags, split_tags};
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn it_splits_a_pasted_text() {
+        assert_eq!(
+            split_tags("rust, leptos;\n wasm,,"),
+            tags(&["rust", "leptos", "wasm"])
+        );
+        assert!(split_tags(" , ").is_empty());
+    }
+
+    #[test]
+    fn it_adds_the_missing_tags() {
+        let current = tags(&["rust"]);
+
+        assert_eq!(
+            add_tags(&current, tags(&["Rust", "wasm", "wasm"]), None, false),
+            tags(&["rust", "wasm"])
+        );
+        assert_eq!(
+            add_tags(&current, tags(&["Rust"]), None, true),
+            tags(&["rust", "Rust"])
+        );
+    }
+
+    #[test]
+    fn it_stops_at_the_max_tags() {
+        assert_eq!(
+            add_tags(&tags(&["a"]), tags(&["b", "c"]), Some(2), false),
+            tags(&["a", "b"])
+        );
+    }
+}
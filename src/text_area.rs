@@ -0,0 +1,152 @@
+use std::{ops::Deref, sync::Arc};
+
+use leptos::{
+    component, create_effect, create_memo, create_node_ref, event_target_value, html::Textarea,
+    view, Callback, IntoView, SignalGet, SignalGetUntracked, SignalWith,
+};
+
+use crate::{
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+        validators::max_length::MAX_LENGTH_NAME,
+    },
+    id::resolve_id,
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-text-area flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+/// line height of the `text-sm` textarea, in rem
+const LINE_HEIGHT_REM: f32 = 1.25;
+
+#[component]
+pub fn TextArea(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "")] placeholder: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(default = 3)] min_rows: u32,
+    /// grows with the content up to `max_rows`, scrolling past it
+    #[prop(optional)]
+    auto_grow: bool,
+    #[prop(optional)] max_rows: Option<u32>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<String>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "text-area");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let text_area_ref = create_node_ref::<Textarea>();
+    let control_value = control.value;
+
+    // notify the new value to the control while the user types
+    let on_input = {
+        let control_ref = Arc::clone(&control);
+
+        move |ev| {
+            let input_value = event_target_value(&ev);
+
+            control_ref.mark_as_dirty();
+
+            if input_value.is_empty() {
+                control_ref.set_value(None);
+            } else {
+                control_ref.set_value(Some(input_value));
+            }
+        }
+    };
+
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev| {
+            control_ref.mark_as_touched();
+        }
+    };
+
+    // reflect control changes into the element and fit its height to the content
+    create_effect(move |_| {
+        let value = control_value.get().unwrap_or_default();
+
+        if let Some(text_area) = text_area_ref.get() {
+            if text_area.value() != value {
+                text_area.set_value(&value);
+            }
+
+            if auto_grow {
+                let style = text_area.deref().style();
+                let _ = style.set_property("height", "auto");
+                let _ = style.set_property("height", &format!("{}px", text_area.scroll_height()));
+            }
+        }
+    });
+
+    let max_height =
+        max_rows.map(|rows| format!("max-height: {}rem", rows as f32 * LINE_HEIGHT_REM));
+
+    // the counter shows the limit of the `MaxLength` validator of the control
+    let max_length = control
+        .constraint(MAX_LENGTH_NAME)
+        .and_then(|meta| meta.get("max_length"));
+
+    let length =
+        move || control_value.with(|value| value.as_ref().map_or(0, |value| value.chars().count()));
+
+    let over_length = create_memo({
+        let control_ref = Arc::clone(&control);
+
+        move |_| control_ref.has_err(MAX_LENGTH_NAME)
+    });
+
+    // field specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the textarea while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    view! {
+        <div class=class_props_by_state>
+            <label for=form_id.clone() class="text-xs text-slate-900">{label}</label>
+            <textarea
+                node_ref=text_area_ref
+                class="outline-none text-sm bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
+                class:resize-none=auto_grow
+                class:overflow-y-auto=auto_grow
+                rows=min_rows
+                style=max_height
+                placeholder=placeholder
+                id=form_id
+                aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                aria-describedby=described_by
+                on:input=on_input
+                on:blur=on_blur>
+                {control_value.get_untracked().unwrap_or_default()}
+            </textarea>
+
+            <div class="flex flex-row justify-between gap-x-2">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+
+                {max_length.map(|max_length| view! {
+                    <div class="lumx-text-area-counter shrink-0 text-xs"
+                        class=("text-red-600", move || over_length.get())
+                        class=("text-slate-500", move || !over_length.get())>
+                        {length} " / " {max_length}
+                    </div>
+                })}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,215 @@
+//! Formatting, parsing and stepping of times of day, shared by the time inputs.
+
+use chrono::{NaiveTime, Timelike};
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// Whether times are shown on a 12 or a 24 hour clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HourCycle {
+    /// `9:30 PM`
+    H12,
+    /// `21:30`
+    #[default]
+    H24,
+}
+
+impl HourCycle {
+    /// Pattern hinting the expected entry, e.g. `hh:mm am`.
+    pub fn pattern(&self, with_seconds: bool) -> &'static str {
+        match (self, with_seconds) {
+            (HourCycle::H12, false) => "hh:mm am",
+            (HourCycle::H12, true) => "hh:mm:ss am",
+            (HourCycle::H24, false) => "hh:mm",
+            (HourCycle::H24, true) => "hh:mm:ss",
+        }
+    }
+}
+
+pub fn format_time(time: NaiveTime, cycle: HourCycle, with_seconds: bool) -> String {
+    let (minute, second) = (time.minute(), time.second());
+
+    let clock = match cycle {
+        HourCycle::H24 => format!("{:02}:{minute:02}", time.hour()),
+        HourCycle::H12 => format!("{}:{minute:02}", time.hour12().1),
+    };
+
+    let clock = match with_seconds {
+        true => format!("{clock}:{second:02}"),
+        false => clock,
+    };
+
+    match cycle {
+        HourCycle::H24 => clock,
+        HourCycle::H12 if time.hour12().0 => format!("{clock} PM"),
+        HourCycle::H12 => format!("{clock} AM"),
+    }
+}
+
+/// Parses a typed time on either clock, e.g. `21:30`, `9:30 pm`, `9pm` or `21.30.15`.
+pub fn parse_time(value: &str) -> Option<NaiveTime> {
+    let value = value.trim().to_lowercase();
+
+    let (clock, meridiem) = match value.strip_suffix('m') {
+        Some(rest) if rest.ends_with('a') => (rest.trim_end_matches('a'), Some(false)),
+        Some(rest) if rest.ends_with('p') => (rest.trim_end_matches('p'), Some(true)),
+        _ => (value.as_str(), None),
+    };
+
+    let parts: Vec<&str> = clock.trim().split([':', '.', 'h']).map(str::trim).collect();
+
+    let number = |idx: usize| -> Option<u32> {
+        match parts.get(idx) {
+            None | Some(&"") if idx > 0 => Some(0),
+            Some(part) if part.len() <= 2 && part.chars().all(|ch| ch.is_ascii_digit()) => {
+                part.parse().ok()
+            }
+            _ => None,
+        }
+    };
+
+    if parts.len() > 3 {
+        return None;
+    }
+
+    let (hour, minute, second) = (number(0)?, number(1)?, number(2)?);
+
+    let hour = match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(false) => hour % 12,
+        Some(true) => hour % 12 + 12,
+        None => hour,
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Length of a step in seconds, between a minute and a whole day.
+pub fn step_seconds(minute_step: u32) -> u32 {
+    minute_step.clamp(1, 24 * 60) * 60
+}
+
+/// Rounds to the closest step counted from midnight, staying on the last step of the day.
+pub fn snap_time(time: NaiveTime, minute_step: u32) -> NaiveTime {
+    let step = step_seconds(minute_step);
+    let seconds = time.num_seconds_from_midnight();
+    let last_step = (SECONDS_PER_DAY - 1) / step * step;
+    let snapped = ((seconds + step / 2) / step * step).min(last_step);
+
+    NaiveTime::from_num_seconds_from_midnight_opt(snapped, 0).unwrap_or(time)
+}
+
+/// Time kept once typed, rounded to the steps unless the seconds are shown.
+pub fn settle_time(time: NaiveTime, minute_step: u32, with_seconds: bool) -> NaiveTime {
+    match with_seconds || minute_step <= 1 {
+        true => time,
+        false => snap_time(time, minute_step),
+    }
+}
+
+/// Times offered every `minute_step` minutes, starting at midnight.
+pub fn time_slots(minute_step: u32) -> Vec<NaiveTime> {
+    let step = step_seconds(minute_step);
+
+    (0..SECONDS_PER_DAY)
+        .step_by(step as usize)
+        .filter_map(|seconds| NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0))
+        .collect()
+}
+
+/// Keeps a time within the optional bounds.
+pub fn clamp_time(time: NaiveTime, min: Option<NaiveTime>, max: Option<NaiveTime>) -> NaiveTime {
+    let time = min.map_or(time, |min| time.max(min));
+
+    max.map_or(time, |max| time.min(max))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveTime;
+
+    use crate::time::{
+        clamp_time, format_time, parse_time, settle_time, snap_time, time_slots, HourCycle,
+    };
+
+    fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn it_formats_on_both_clocks() {
+        assert_eq!(format_time(time(21, 5, 0), HourCycle::H24, false), "21:05");
+        assert_eq!(
+            format_time(time(21, 5, 9), HourCycle::H24, true),
+            "21:05:09"
+        );
+        assert_eq!(
+            format_time(time(21, 5, 0), HourCycle::H12, false),
+            "9:05 PM"
+        );
+        assert_eq!(
+            format_time(time(0, 30, 0), HourCycle::H12, false),
+            "12:30 AM"
+        );
+        assert_eq!(
+            format_time(time(12, 0, 0), HourCycle::H12, false),
+            "12:00 PM"
+        );
+    }
+
+    #[test]
+    fn it_parses_typed_times() {
+        assert_eq!(parse_time("21:30"), Some(time(21, 30, 0)));
+        assert_eq!(parse_time("9:30 pm"), Some(time(21, 30, 0)));
+        assert_eq!(parse_time("9PM"), Some(time(21, 0, 0)));
+        assert_eq!(parse_time("12:15 am"), Some(time(0, 15, 0)));
+        assert_eq!(parse_time("12 pm"), Some(time(12, 0, 0)));
+        assert_eq!(parse_time("21.30.15"), Some(time(21, 30, 15)));
+        assert_eq!(parse_time("7h"), Some(time(7, 0, 0)));
+        assert_eq!(parse_time("13 pm"), None);
+        assert_eq!(parse_time("24:00"), None);
+        assert_eq!(parse_time("9:3x"), None);
+        assert_eq!(parse_time(""), None);
+    }
+
+    #[test]
+    fn it_snaps_to_the_minute_steps() {
+        assert_eq!(snap_time(time(9, 7, 0), 15), time(9, 0, 0));
+        assert_eq!(snap_time(time(9, 8, 0), 15), time(9, 15, 0));
+        assert_eq!(snap_time(time(23, 59, 0), 15), time(23, 45, 0));
+        assert_eq!(snap_time(time(9, 7, 40), 1), time(9, 8, 0));
+    }
+
+    #[test]
+    fn it_keeps_typed_seconds() {
+        assert_eq!(settle_time(time(21, 30, 15), 15, true), time(21, 30, 15));
+        assert_eq!(settle_time(time(21, 37, 0), 15, false), time(21, 30, 0));
+        assert_eq!(settle_time(time(21, 37, 0), 1, false), time(21, 37, 0));
+    }
+
+    #[test]
+    fn it_snaps_to_a_listed_slot() {
+        // 7 minutes do not divide the day, the last slot is 23:55
+        let slots = time_slots(7);
+        assert_eq!(slots.last(), Some(&time(23, 55, 0)));
+        assert_eq!(snap_time(time(23, 59, 0), 7), time(23, 55, 0));
+        assert!(slots.contains(&snap_time(time(23, 59, 0), 7)));
+
+        // steps past a day are a single slot at midnight
+        assert_eq!(time_slots(2000), vec![time(0, 0, 0)]);
+        assert_eq!(snap_time(time(18, 0, 0), 2000), time(0, 0, 0));
+    }
+
+    #[test]
+    fn it_lists_and_clamps_the_slots() {
+        let slots = time_slots(30);
+
+        assert_eq!(slots.len(), 48);
+        assert_eq!(slots[1], time(0, 30, 0));
+        assert_eq!(slots[47], time(23, 30, 0));
+        assert_eq!(
+            clamp_time(time(7, 0, 0), Some(time(9, 0, 0)), None),
+            time(9, 0, 0)
+        );
+    }
+}
@@ -0,0 +1,547 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Offset, TimeZone, Timelike, Utc, Weekday};
+use leptos::{
+    component, create_effect, create_memo, create_node_ref, create_rw_signal, document,
+    ev::{FocusEvent, KeyboardEvent, MouseEvent},
+    event_target_value,
+    html::{Div, Input},
+    use_context, view, Callback, CollectView, IntoView, Show, SignalGet, SignalGetUntracked,
+    SignalSet, SignalWith,
+};
+
+use crate::{
+    calendar::CalendarLocale,
+    date_picker::{DatePicker, DatePickerProps},
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    icons::{check::Check, clock::Clock},
+    id::resolve_id,
+    listbox::{first_enabled, last_enabled, move_active, PAGE_SIZE},
+    popover::{use_popover, Popover},
+    time::{
+        clamp_time, format_time, parse_time, settle_time, snap_time, step_seconds, time_slots,
+        HourCycle,
+    },
+};
+
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-time-picker flex flex-col rounded-md border box-border p-2.5 bg-white mb-2.5 focus-within:border";
+
+/// Time of day field with a list of times every `minute_step` minutes.
+///
+/// Typed times are rounded to the minute steps once the field is left. Like the other
+/// inputs, typed times out of `min` and `max` are kept so validators like `MinTime` can report them.
+#[component]
+pub fn TimePicker(
+    #[prop(default = "label")] label: &'static str,
+    /// defaults to the pattern of the clock, e.g. `hh:mm`
+    #[prop(optional)]
+    placeholder: Option<&'static str>,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] hour_cycle: HourCycle,
+    #[prop(default = 15)] minute_step: u32,
+    #[prop(optional)] with_seconds: bool,
+    #[prop(optional)] min: Option<NaiveTime>,
+    #[prop(optional)] max: Option<NaiveTime>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<NaiveTime>>,
+) -> impl IntoView {
+    let form_id = resolve_id(id, "time-picker");
+    let listbox_id = format!("{form_id}-listbox");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let picker_ref = create_node_ref::<Div>();
+    let input_ref = create_node_ref::<Input>();
+    let control_value = control.value;
+    let placeholder = placeholder.unwrap_or(hour_cycle.pattern(with_seconds));
+
+    let slots = time_slots(minute_step);
+    let disabled_flags: Vec<bool> = slots
+        .iter()
+        .map(|slot| clamp_time(*slot, min, max) != *slot)
+        .collect();
+    let slot_count = slots.len();
+
+    let active = create_rw_signal(None::<usize>);
+    let popover = use_popover(picker_ref, move || active.set(None));
+
+    let option_id = {
+        let form_id = form_id.clone();
+
+        move |idx: usize| format!("{form_id}-option-{idx}")
+    };
+
+    let display_value = move |value: Option<NaiveTime>| {
+        value
+            .map(|value| format_time(value, hour_cycle, with_seconds))
+            .unwrap_or_default()
+    };
+
+    // slot holding the current time once rounded to the steps
+    let selected_idx = create_memo(move |_| {
+        control_value.get().map(|value| {
+            let seconds = snap_time(value, minute_step).num_seconds_from_midnight();
+
+            (seconds / step_seconds(minute_step)) as usize
+        })
+    });
+
+    let open_list = {
+        let disabled_flags = disabled_flags.clone();
+
+        move || {
+            let start = selected_idx
+                .get_untracked()
+                .filter(|idx| *idx < slot_count && !disabled_flags[*idx])
+                .or_else(|| first_enabled(&disabled_flags));
+
+            active.set(start);
+            popover.open();
+        }
+    };
+
+    let select = {
+        let control_ref = Arc::clone(&control);
+        let slots = slots.clone();
+
+        move |idx: usize| {
+            control_ref.mark_as_dirty();
+            control_ref.set_value(slots.get(idx).copied());
+            popover.close();
+        }
+    };
+
+    // notify the parsed time to the control while the user types
+    let on_input = {
+        let control_ref = Arc::clone(&control);
+
+        move |ev| {
+            let input_value = event_target_value(&ev);
+
+            control_ref.mark_as_dirty();
+            control_ref.set_value(parse_time(&input_value));
+        }
+    };
+
+    // round to the steps, typed seconds being kept, then show the time on the configured clock
+    let on_blur = {
+        let control_ref = Arc::clone(&control);
+
+        move |_ev: FocusEvent| {
+            control_ref.mark_as_touched();
+
+            let value = control_value.get_untracked();
+            let adjusted = value.map(|value| settle_time(value, minute_step, with_seconds));
+
+            if adjusted != value {
+                control_ref.set_value(adjusted);
+            }
+
+            if let Some(input) = input_ref.get_untracked() {
+                input.set_value(&display_value(adjusted));
+            }
+        }
+    };
+
+    let on_keydown = {
+        let select = select.clone();
+        let open_list = open_list.clone();
+        let disabled_flags = disabled_flags.clone();
+
+        move |ev: KeyboardEvent| {
+            let key = ev.key();
+
+            if !popover.is_open_untracked() {
+                match key.as_str() {
+                    "ArrowDown" | "ArrowUp" => open_list(),
+                    _ => return,
+                }
+
+                ev.prevent_default();
+                return;
+            }
+
+            let current = active.get_untracked();
+
+            match key.as_str() {
+                "ArrowDown" => active.set(move_active(&disabled_flags, current, 1)),
+                "ArrowUp" => active.set(move_active(&disabled_flags, current, -1)),
+                "PageDown" => active.set(move_active(&disabled_flags, current, PAGE_SIZE as isize)),
+                "PageUp" => {
+                    active.set(move_active(&disabled_flags, current, -(PAGE_SIZE as isize)))
+                }
+                "Home" => active.set(first_enabled(&disabled_flags)),
+                "End" => active.set(last_enabled(&disabled_flags)),
+                "Enter" => match current {
+                    Some(idx) => select(idx),
+                    None => return,
+                },
+                "Escape" => popover.close(),
+                "Tab" => {
+                    popover.close();
+                    return;
+                }
+                _ => return,
+            }
+
+            ev.prevent_default();
+        }
+    };
+
+    let on_toggle = move |_ev: MouseEvent| {
+        match popover.is_open_untracked() {
+            true => popover.close(),
+            false => open_list(),
+        }
+
+        if let Some(input) = input_ref.get_untracked() {
+            let _ = input.focus();
+        }
+    };
+
+    // keep the active option visible while navigating
+    create_effect({
+        let option_id = option_id.clone();
+
+        move |_| {
+            if let Some(idx) = active.get() {
+                if let Some(element) = document().get_element_by_id(&option_id(idx)) {
+                    element.scroll_into_view_with_bool(false);
+                }
+            }
+        }
+    });
+
+    // reflect control changes into the element, only writing when the typed
+    // text does not already stand for the time so the caret is not moved.
+    create_effect(move |_| {
+        let value = control_value.get();
+
+        if let Some(input) = input_ref.get() {
+            if parse_time(&input.value()) != value {
+                input.set_value(&display_value(value));
+            }
+        }
+    });
+
+    // field specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the input while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    let active_descendant = {
+        let option_id = option_id.clone();
+
+        move || active.get().filter(|_| popover.is_open()).map(&option_id)
+    };
+
+    let render_options = move || {
+        slots
+            .iter()
+            .enumerate()
+            .map(|(idx, slot)| {
+                let disabled = disabled_flags[idx];
+                let is_active = move || active.get() == Some(idx);
+                let is_selected = move || selected_idx.get() == Some(idx);
+                let select = select.clone();
+
+                view! {
+                    <li id=option_id(idx)
+                        role="option"
+                        aria-selected=move || is_selected().to_string()
+                        aria-disabled=disabled.then_some("true")
+                        class="time-picker-option flex flex-row items-center justify-between text-sm text-slate-900 py-2 px-2"
+                        class=("bg-slate-200", is_active)
+                        class=("cursor-pointer", !disabled)
+                        class=("opacity-50", disabled)
+                        on:mousedown=|ev: MouseEvent| ev.prevent_default()
+                        on:mousemove=move |_ev: MouseEvent| {
+                            if !disabled && !is_active() {
+                                active.set(Some(idx));
+                            }
+                        }
+                        on:click=move |_ev: MouseEvent| {
+                            if !disabled {
+                                select(idx);
+                            }
+                        }>
+                        <span>{format_time(*slot, hour_cycle, false)}</span>
+                        <Show when=is_selected>
+                            <Check class="w-4 h-4 text-slate-900" />
+                        </Show>
+                    </li>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div node_ref=picker_ref class="lumx-time-picker-relative relative">
+            <div class=class_props_by_state>
+                <label for=form_id.clone() class="text-xs text-slate-900">{label}</label>
+                <div class="lumx-time-picker-field flex flex-row items-center gap-x-1">
+                    <input
+                        node_ref=input_ref
+                        class="grow min-w-0 outline-none text-sm bg-white text-slate-900 placeholder:text-sm placeholder:text-slate-400"
+                        type="text"
+                        role="combobox"
+                        autocomplete="off"
+                        aria-autocomplete="none"
+                        aria-haspopup="listbox"
+                        aria-controls=listbox_id.clone()
+                        aria-expanded=move || popover.is_open().to_string()
+                        aria-activedescendant=active_descendant
+                        placeholder=placeholder
+                        id=form_id
+                        aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                        aria-describedby=described_by
+                        on:input=on_input
+                        on:blur=on_blur
+                        on:keydown=on_keydown
+                        value=display_value(control_value.get_untracked()) />
+
+                    <button type="button"
+                        tabindex="-1"
+                        aria-label="choose time"
+                        on:click=on_toggle
+                        class="lumx-time-picker-toggle shrink-0 cursor-pointer rounded-full p-0.5 text-slate-900 hover:bg-slate-200">
+                        <Clock class="w-5 h-5" />
+                    </button>
+                </div>
+
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
+
+            <Popover state=popover>
+                <ul id=listbox_id.clone()
+                    role="listbox"
+                    aria-label="times"
+                    tabindex="-1"
+                    class="time-picker-overlay-panel w-full absolute -mt-1.5 z-10 max-h-60 overflow-y-auto rounded-md border box-border border-slate-200 bg-white py-1">
+                    {render_options.clone()}
+                </ul>
+            </Popover>
+        </div>
+    }
+}
+
+/// Date and time fields bound to a zoned date time control.
+///
+/// The fields show the wall time in `time_zone`, the control is empty until both
+/// fields hold a value or when the wall time does not exist in the zone, e.g. during
+/// a daylight saving transition.
+#[component]
+pub fn DateTimePicker<Tz>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(optional, into)] id: Option<String>,
+    /// zone of the wall time shown, e.g. `Utc`, `Local` or a `FixedOffset`
+    time_zone: Tz,
+    /// shows the offset of the zone next to the time
+    #[prop(optional)]
+    show_offset: bool,
+    #[prop(optional)] min_date: Option<NaiveDate>,
+    #[prop(optional)] max_date: Option<NaiveDate>,
+    /// bounds of the time, whatever the date
+    #[prop(optional)]
+    min_time: Option<NaiveTime>,
+    #[prop(optional)] max_time: Option<NaiveTime>,
+    /// names and format, defaults to a provided `CalendarLocale` or to `CalendarLocale::en`
+    #[prop(optional)]
+    locale: Option<CalendarLocale>,
+    /// overrides the first day of the week of the locale
+    #[prop(optional)]
+    first_day: Option<Weekday>,
+    #[prop(optional)] hour_cycle: HourCycle,
+    #[prop(default = 15)] minute_step: u32,
+    #[prop(optional)] with_seconds: bool,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    control: Arc<FormControl<DateTime<Tz>>>,
+) -> impl IntoView
+where
+    Tz: TimeZone + 'static,
+{
+    let form_id = resolve_id(id, "date-time-picker");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let control_value = control.value;
+
+    let locale = locale
+        .or_else(use_context::<CalendarLocale>)
+        .unwrap_or_default();
+    let locale = first_day.map_or(locale, |first_day| locale.with_first_day(first_day));
+
+    let wall_time = {
+        let time_zone = time_zone.clone();
+
+        move |value: &DateTime<Tz>| value.with_timezone(&time_zone).naive_local()
+    };
+
+    let initial = control_value.get_untracked().map(|value| wall_time(&value));
+    // the picker fields only share the control, no thread ever sees it
+    #[allow(clippy::arc_with_non_send_sync)]
+    let date_control = Arc::new(FormControl::new(initial.map(|value| value.date()), vec![]));
+    #[allow(clippy::arc_with_non_send_sync)]
+    let time_control = Arc::new(FormControl::new(initial.map(|value| value.time()), vec![]));
+    let (date_value, time_value) = (date_control.value, time_control.value);
+
+    // combine the fields into the control
+    create_effect({
+        let control_ref = Arc::clone(&control);
+        let time_zone = time_zone.clone();
+
+        move |_| {
+            let combined = date_value
+                .get()
+                .zip(time_value.get())
+                .and_then(|(date, time)| {
+                    time_zone
+                        .from_local_datetime(&date.and_time(time))
+                        .earliest()
+                });
+
+            if control_value.get_untracked() != combined {
+                control_ref.set_value(combined);
+            }
+        }
+    });
+
+    // reflect control changes into the fields
+    create_effect({
+        let wall_time = wall_time.clone();
+
+        move |_| {
+            let value = control_value.get().map(|value| wall_time(&value));
+            let (date, time) = (
+                value.map(|value| value.date()),
+                value.map(|value| value.time()),
+            );
+
+            // an empty control keeps a partially filled entry
+            if value.is_none() {
+                return;
+            }
+
+            if date_value.get_untracked() != date {
+                date_value.set(date);
+            }
+
+            if time_value.get_untracked() != time {
+                time_value.set(time);
+            }
+        }
+    });
+
+    // the control is touched and dirty as soon as one of the fields is
+    create_effect({
+        let control_ref = Arc::clone(&control);
+        let (date_ref, time_ref) = (Arc::clone(&date_control), Arc::clone(&time_control));
+
+        move |_| {
+            if (date_ref.is_dirty() || time_ref.is_dirty()) && !control_ref.is_dirty() {
+                control_ref.mark_as_dirty();
+            }
+
+            if (date_ref.is_touched() || time_ref.is_touched()) && !control_ref.is_touched() {
+                control_ref.mark_as_touched();
+            }
+        }
+    });
+
+    let offset = move || {
+        control_value
+            .get()
+            .map(|value| value.offset().fix())
+            .unwrap_or_else(|| {
+                time_zone
+                    .offset_from_utc_datetime(&Utc::now().naive_utc())
+                    .fix()
+            })
+            .to_string()
+    };
+
+    // the bounds are optional props, set on the built props to forward them as they are
+    let date_picker = {
+        let mut props = DatePickerProps::builder()
+            .label("date")
+            .id(format!("{form_id}-date"))
+            .locale(locale)
+            .control(date_control)
+            .build();
+        props.min = min_date;
+        props.max = max_date;
+
+        DatePicker(props)
+    };
+
+    let time_picker = {
+        let mut props = TimePickerProps::builder()
+            .label("time")
+            .id(format!("{form_id}-time"))
+            .hour_cycle(hour_cycle)
+            .minute_step(minute_step)
+            .with_seconds(with_seconds)
+            .control(time_control)
+            .build();
+        props.min = min_time;
+        props.max = max_time;
+
+        TimePicker(props)
+    };
+
+    // the feedback region only describes the group while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
+    view! {
+        <div id=form_id.clone()
+            role="group"
+            aria-labelledby=label_id.clone()
+            aria-invalid=move || visible_err.with(Option::is_some).to_string()
+            aria-describedby=described_by
+            class="lumx-date-time-picker flex flex-col mb-2.5">
+            <div id=label_id class="text-xs text-slate-900 mb-1">{label}</div>
+
+            <div class="flex flex-row flex-wrap items-start gap-x-2">
+                <div class="grow">
+                    {date_picker}
+                </div>
+
+                <div class="grow">
+                    {time_picker}
+                </div>
+
+                <Show when=move || show_offset>
+                    <span class="lumx-date-time-picker-offset self-center text-xs text-slate-500">
+                        {offset.clone()}
+                    </span>
+                </Show>
+            </div>
+
+            <FieldFeedback id=feedback_id hint=hint err=visible_err />
+        </div>
+    }
+}
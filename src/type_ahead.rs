@@ -1,17 +1,34 @@
-use std::sync::Arc;
+use std::{fmt::Display, sync::Arc};
 
 use leptos::{
-    component, create_memo, create_node_ref, create_signal, ev::MouseEvent, event_target_value,
-    html::Div, view, Callable, Callback, For, IntoView, Resource, Show, SignalGet,
-    SignalGetUntracked, SignalSet, Suspense, WriteSignal,
+    component, create_effect, create_memo, create_node_ref, create_rw_signal, create_signal,
+    document,
+    ev::{KeyboardEvent, MouseEvent},
+    event_target_value,
+    html::{Div, Input},
+    slot, view, Callable, Callback, ChildrenFn, CollectView, IntoView, MaybeSignal, Memo,
+    ReadSignal, Resource, RwSignal, Show, Signal, SignalGet, SignalGetUntracked, SignalSet,
+    SignalWith, SignalWithUntracked, Suspense, View, WriteSignal,
 };
-use leptos_use::{is_some, on_click_outside, watch_debounced};
+use leptos_use::{on_click_outside, watch_debounced};
 
 use crate::{
-    forms::control::{AbstractFormControl, FormControl},
-    icons::x_mark::XMark,
+    chip::Chip,
+    field_feedback::{visible_err, FieldFeedback},
+    forms::{
+        control::{AbstractFormControl, FormControl},
+        validator::{ValidatorKey, ValidatorMetadata},
+    },
+    highlight::Highlight,
+    icons::{check::Check, spin::Spin, x_mark::XMark},
+    id::resolve_id,
+    listbox::{first_enabled, last_enabled, move_active, PAGE_SIZE},
+    matcher::{filter_options, Matcher},
 };
 
+const DEFAULT_STYLE_CLASS: &str =
+    "lumx-type-ahead rounded-md border box-border bg-white mb-2.5 focus-within:border";
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct TypeAheadOption {
     pub value: String,
@@ -22,33 +39,540 @@ impl TypeAheadOption {
     pub fn new(value: String, label: String) -> Self {
         Self { value, label }
     }
+
+    /// `option_key` of `TypeAheadOption` suggestions
+    pub fn key(option: Self) -> String {
+        option.value
+    }
+
+    /// `option_label` of `TypeAheadOption` suggestions
+    pub fn label(option: Self) -> String {
+        option.label
+    }
+}
+
+/// Suggestion handed to an `option_template`.
+pub struct TypeAheadOptionContext<T> {
+    pub option: T,
+    /// text of the option, as read by `option_label`
+    pub label: String,
+    /// text typed in the search field, e.g. to `Highlight` it in the label
+    pub query: String,
+}
+
+/// Value of the suggestions resource, either the suggestions themselves or a
+/// `Result` whose error is shown in the panel, with a retry button.
+pub trait Suggestions<T>: Clone + 'static {
+    /// the suggestions or the message of the failure
+    fn into_result(self) -> Result<Vec<T>, String>;
+}
+
+impl<T> Suggestions<T> for Vec<T>
+where
+    T: Clone + 'static,
+{
+    fn into_result(self) -> Result<Vec<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T, E> Suggestions<T> for Result<Vec<T>, E>
+where
+    T: Clone + 'static,
+    E: Display + Clone + 'static,
+{
+    fn into_result(self) -> Result<Vec<T>, String> {
+        self.map_err(|err| err.to_string())
+    }
+}
+
+/// Where the suggestions come from, converted from the `suggestions` prop.
+///
+/// A `Resource` is searched with the typed text sent to `changes`, a list already
+/// in memory, static or signal-backed, is filtered locally with the `matcher`.
+#[derive(Clone)]
+pub enum TypeAheadSource<T: 'static> {
+    Remote {
+        /// the fetched suggestions or the message of the failure, `None` while loading
+        result: Signal<Option<Result<Vec<T>, String>>>,
+        /// fetches the suggestions again, e.g. after a failure
+        refetch: Callback<()>,
+    },
+    Local(MaybeSignal<Vec<T>>),
+}
+
+impl<T, S> From<Resource<String, S>> for TypeAheadSource<T>
+where
+    T: Clone + 'static,
+    S: Suggestions<T>,
+{
+    fn from(suggestions: Resource<String, S>) -> Self {
+        Self::Remote {
+            result: Signal::derive(move || suggestions.get().map(Suggestions::into_result)),
+            refetch: Callback::new(move |_| suggestions.refetch()),
+        }
+    }
+}
+
+impl<T: Clone + 'static> From<Vec<T>> for TypeAheadSource<T> {
+    fn from(options: Vec<T>) -> Self {
+        Self::Local(MaybeSignal::Static(options))
+    }
+}
+
+impl<T: Clone + 'static> From<MaybeSignal<Vec<T>>> for TypeAheadSource<T> {
+    fn from(options: MaybeSignal<Vec<T>>) -> Self {
+        Self::Local(options)
+    }
+}
+
+impl<T: Clone + 'static> From<Signal<Vec<T>>> for TypeAheadSource<T> {
+    fn from(options: Signal<Vec<T>>) -> Self {
+        Self::Local(options.into())
+    }
+}
+
+impl<T: Clone + 'static> From<ReadSignal<Vec<T>>> for TypeAheadSource<T> {
+    fn from(options: ReadSignal<Vec<T>>) -> Self {
+        Self::Local(options.into())
+    }
+}
+
+impl<T: Clone + 'static> From<RwSignal<Vec<T>>> for TypeAheadSource<T> {
+    fn from(options: RwSignal<Vec<T>>) -> Self {
+        Self::Local(options.into())
+    }
+}
+
+impl<T: Clone + 'static> From<Memo<Vec<T>>> for TypeAheadSource<T> {
+    fn from(options: Memo<Vec<T>>) -> Self {
+        Self::Local(options.into())
+    }
+}
+
+/// Shown while the suggestions are loading, instead of the spinner.
+#[slot]
+pub struct TypeAheadLoading {
+    children: ChildrenFn,
+}
+
+/// Shown when no suggestion matches the typed text.
+#[slot]
+pub struct TypeAheadEmpty {
+    children: ChildrenFn,
+}
+
+/// Shown when the suggestions could not be loaded, next to the retry button.
+#[slot]
+pub struct TypeAheadError {
+    children: ChildrenFn,
+}
+
+/// Shown until `min_chars` characters are typed.
+#[slot]
+pub struct TypeAheadMinChars {
+    children: ChildrenFn,
+}
+
+/// Reads the key and the label of the suggestions.
+struct Extractors<T: 'static> {
+    key: Callback<T, String>,
+    label: Callback<T, String>,
+}
+
+impl<T: 'static> Clone for Extractors<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
+impl<T: 'static> Copy for Extractors<T> {}
+
+impl<T> Extractors<T>
+where
+    T: Clone + 'static,
+{
+    fn key(&self, item: &T) -> String {
+        self.key.call(item.clone())
+    }
+
+    fn label(&self, item: &T) -> String {
+        self.label.call(item.clone())
+    }
+}
+
+/// Adds the option when no selected one shares its key and there is room left, removes it otherwise.
+fn toggle_keyed<T, K>(selected: &[T], option: &T, key: K, max_selected: Option<usize>) -> Vec<T>
+where
+    T: Clone,
+    K: Fn(&T) -> String,
+{
+    let option_key = key(option);
+    let mut selected = selected.to_vec();
+
+    match selected.iter().position(|item| key(item) == option_key) {
+        Some(idx) => {
+            selected.remove(idx);
+        }
+        None if max_selected.is_some_and(|max| selected.len() >= max) => {}
+        None => selected.push(option.clone()),
+    }
+
+    selected
+}
+
+/// Message announced to screen readers once the suggestions are loaded.
+fn result_count_message(count: usize) -> String {
+    match count {
+        0 => String::from("No results"),
+        1 => String::from("1 result available"),
+        count => format!("{count} results available"),
+    }
+}
+
+/// Hint shown until enough characters are typed.
+fn min_chars_message(min_chars: usize) -> String {
+    match min_chars {
+        1 => String::from("Type at least 1 character"),
+        min_chars => format!("Type at least {min_chars} characters"),
+    }
+}
+
+/// Opens the panel from the trigger with the keys of the combobox pattern.
+fn opens_panel(ev: &KeyboardEvent) -> bool {
+    matches!(ev.key().as_str(), "ArrowDown" | "ArrowUp" | "Enter" | " ")
+}
+
+/// Single suggestion picked among the ones fetched for the typed text, or among
+/// a local list filtered by the `matcher`.
+///
+/// The control holds the picked suggestion itself, read through `option_key` and
+/// `option_label`, e.g. `TypeAheadOption::key` and `TypeAheadOption::label`.
 #[component]
-pub fn TypeAhead(
+pub fn TypeAhead<T>(
     #[prop(default = "label")] label: &'static str,
     #[prop(default = "")] placeholder: &'static str,
     #[prop(default = 800.0)] debounced_ms: f64,
-    suggestions: Resource<String, Vec<TypeAheadOption>>,
-    changes: WriteSignal<String>,
-    control: Arc<FormControl<TypeAheadOption>>,
-) -> impl IntoView {
-    let type_ahead_ref = create_node_ref::<Div>();
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    /// identifies a suggestion, e.g. the id of a record
+    #[prop(into)]
+    option_key: Callback<T, String>,
+    /// text shown for a suggestion
+    #[prop(into)]
+    option_label: Callback<T, String>,
+    /// renders a suggestion, e.g. with an avatar or a secondary line, defaults
+    /// to the label with the typed text highlighted
+    #[prop(optional, into)]
+    option_template: Option<Callback<TypeAheadOptionContext<T>, View>>,
+    /// characters to type before the suggestions are searched
+    #[prop(optional)]
+    min_chars: usize,
+    #[prop(optional)] type_ahead_loading: Option<TypeAheadLoading>,
+    #[prop(optional)] type_ahead_empty: Option<TypeAheadEmpty>,
+    #[prop(optional)] type_ahead_error: Option<TypeAheadError>,
+    #[prop(optional)] type_ahead_min_chars: Option<TypeAheadMinChars>,
+    /// a `Resource` searched with the typed text, or the list to filter locally
+    #[prop(into)]
+    suggestions: TypeAheadSource<T>,
+    /// receives the typed text to search, for a `Resource` only
+    #[prop(optional)]
+    changes: Option<WriteSignal<String>>,
+    /// matches the typed text against the labels of a local list
+    #[prop(optional)]
+    matcher: Matcher,
+    control: Arc<FormControl<T>>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+{
+    let extractors = Extractors {
+        key: option_key,
+        label: option_label,
+    };
+    let control_value = control.value;
 
-    let selected_value = create_memo({
+    let on_select = Callback::new({
         let control_ref = Arc::clone(&control);
 
-        move |_| control_ref.value
+        move |option: T| {
+            control_ref.mark_as_dirty();
+            control_ref.set_value(Some(option));
+        }
+    });
+
+    let on_clear = Callback::new({
+        let control_ref = Arc::clone(&control);
+
+        move |_| {
+            control_ref.mark_as_dirty();
+            control_ref.set_value(None);
+        }
     });
 
+    let selected_label =
+        move || control_value.with(|value| value.as_ref().map(|option| extractors.label(option)));
+
+    view! {
+        <TypeAheadShell
+            label=label
+            placeholder=placeholder
+            debounced_ms=debounced_ms
+            id=id
+            hint=hint
+            err_message=err_message
+            extractors=extractors
+            option_template=option_template
+            min_chars=min_chars
+            loading=type_ahead_loading.map(|slot| slot.children)
+            empty=type_ahead_empty.map(|slot| slot.children)
+            error=type_ahead_error.map(|slot| slot.children)
+            min_chars_hint=type_ahead_min_chars.map(|slot| slot.children)
+            suggestions=suggestions
+            changes=changes
+            matcher=matcher
+            on_select=on_select
+            close_on_select=true
+            on_clear=on_clear
+            control=control>
+            <div class="type-ahead-value text-sm text-slate-900 min-h-5">
+                {selected_label}
+            </div>
+        </TypeAheadShell>
+    }
+}
+
+/// Several suggestions picked one after another, shown as removable chips.
+///
+/// Picking an already selected suggestion removes it, once `max_selected` is reached
+/// the remaining suggestions can no longer be picked.
+#[component]
+pub fn MultiTypeAhead<T>(
+    #[prop(default = "label")] label: &'static str,
+    #[prop(default = "")] placeholder: &'static str,
+    #[prop(default = 800.0)] debounced_ms: f64,
+    #[prop(optional, into)] id: Option<String>,
+    /// no more suggestions can be picked once reached
+    #[prop(optional)]
+    max_selected: Option<usize>,
+    #[prop(optional)] hint: Option<&'static str>,
+    /// message for a validation error, defaults to the built-in messages
+    #[prop(optional, into)]
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    /// identifies a suggestion, e.g. the id of a record
+    #[prop(into)]
+    option_key: Callback<T, String>,
+    /// text shown for a suggestion
+    #[prop(into)]
+    option_label: Callback<T, String>,
+    /// renders a suggestion, e.g. with an avatar or a secondary line, defaults
+    /// to the label with the typed text highlighted
+    #[prop(optional, into)]
+    option_template: Option<Callback<TypeAheadOptionContext<T>, View>>,
+    /// characters to type before the suggestions are searched
+    #[prop(optional)]
+    min_chars: usize,
+    #[prop(optional)] type_ahead_loading: Option<TypeAheadLoading>,
+    #[prop(optional)] type_ahead_empty: Option<TypeAheadEmpty>,
+    #[prop(optional)] type_ahead_error: Option<TypeAheadError>,
+    #[prop(optional)] type_ahead_min_chars: Option<TypeAheadMinChars>,
+    /// a `Resource` searched with the typed text, or the list to filter locally
+    #[prop(into)]
+    suggestions: TypeAheadSource<T>,
+    /// receives the typed text to search, for a `Resource` only
+    #[prop(optional)]
+    changes: Option<WriteSignal<String>>,
+    /// matches the typed text against the labels of a local list
+    #[prop(optional)]
+    matcher: Matcher,
+    control: Arc<FormControl<Vec<T>>>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+{
+    let extractors = Extractors {
+        key: option_key,
+        label: option_label,
+    };
+    let control_value = control.value;
+
+    let selected = move || control_value.get().unwrap_or_default();
+    let key = move |option: &T| extractors.key(option);
+
+    let set_selected = {
+        let control_ref = Arc::clone(&control);
+
+        move |selected: Vec<T>| {
+            control_ref.mark_as_dirty();
+            control_ref.set_value((!selected.is_empty()).then_some(selected));
+        }
+    };
+
+    // removes the last chip
+    let on_trigger_keydown = Callback::new({
+        let set_selected = set_selected.clone();
+
+        move |ev: KeyboardEvent| {
+            if ev.key() == "Backspace" {
+                if let Some(last) = selected().last() {
+                    ev.prevent_default();
+                    set_selected(toggle_keyed(&selected(), last, key, None));
+                }
+            }
+        }
+    });
+
+    // the panel stays open so several suggestions can be picked in a row
+    let on_select = Callback::new({
+        let set_selected = set_selected.clone();
+
+        move |option: T| {
+            set_selected(toggle_keyed(&selected(), &option, key, max_selected));
+        }
+    });
+
+    let contains = move |selected: &[T], option: &T| {
+        let option_key = key(option);
+
+        selected.iter().any(|item| key(item) == option_key)
+    };
+
+    let is_selected = Callback::new(move |option: T| {
+        control_value.with(|value| value.as_ref().is_some_and(|value| contains(value, &option)))
+    });
+
+    let is_locked = Callback::new(move |option: T| {
+        control_value.with(|value| {
+            let selected = value.as_deref().unwrap_or_default();
+
+            max_selected.is_some_and(|max| selected.len() >= max) && !contains(selected, &option)
+        })
+    });
+
+    let render_chips = move || {
+        selected()
+            .into_iter()
+            .map(|option| {
+                let set_selected = set_selected.clone();
+
+                view! {
+                    <Chip label=extractors.label(&option)
+                        on_remove=move |_| set_selected(toggle_keyed(&selected(), &option, key, None)) />
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <TypeAheadShell
+            label=label
+            placeholder=placeholder
+            debounced_ms=debounced_ms
+            id=id
+            hint=hint
+            err_message=err_message
+            extractors=extractors
+            option_template=option_template
+            min_chars=min_chars
+            loading=type_ahead_loading.map(|slot| slot.children)
+            empty=type_ahead_empty.map(|slot| slot.children)
+            error=type_ahead_error.map(|slot| slot.children)
+            min_chars_hint=type_ahead_min_chars.map(|slot| slot.children)
+            suggestions=suggestions
+            changes=changes
+            matcher=matcher
+            on_select=on_select
+            on_trigger_keydown=on_trigger_keydown
+            multiple=true
+            is_selected=is_selected
+            is_locked=is_locked
+            control=control>
+            <div class="type-ahead-chips flex flex-row flex-wrap gap-1 min-h-5">
+                {render_chips.clone()}
+            </div>
+        </TypeAheadShell>
+    }
+}
+
+/// Field shared by the single and multi type aheads: the trigger showing the label and
+/// the selection, the search panel and the validation feedback.
+#[component]
+fn TypeAheadShell<T, V>(
+    label: &'static str,
+    placeholder: &'static str,
+    debounced_ms: f64,
+    id: Option<String>,
+    hint: Option<&'static str>,
+    err_message: Option<Callback<(ValidatorKey, ValidatorMetadata), String>>,
+    extractors: Extractors<T>,
+    option_template: Option<Callback<TypeAheadOptionContext<T>, View>>,
+    min_chars: usize,
+    loading: Option<ChildrenFn>,
+    empty: Option<ChildrenFn>,
+    error: Option<ChildrenFn>,
+    min_chars_hint: Option<ChildrenFn>,
+    suggestions: TypeAheadSource<T>,
+    changes: Option<WriteSignal<String>>,
+    matcher: Matcher,
+    #[prop(into)] on_select: Callback<T>,
+    /// the panel closes once a suggestion is picked
+    #[prop(optional)]
+    close_on_select: bool,
+    /// shows a clear button while the control has a value
+    #[prop(optional, into)]
+    on_clear: Option<Callback<()>>,
+    /// keys of the trigger other than the ones opening the panel
+    #[prop(optional, into)]
+    on_trigger_keydown: Option<Callback<KeyboardEvent>>,
+    #[prop(optional)] multiple: bool,
+    #[prop(optional, into)] is_selected: Option<Callback<T, bool>>,
+    #[prop(optional, into)] is_locked: Option<Callback<T, bool>>,
+    control: Arc<FormControl<V>>,
+    /// the selection, shown in the trigger while the control has a value
+    children: ChildrenFn,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    V: Clone + 'static,
+{
+    let type_ahead_ref = create_node_ref::<Div>();
+    let trigger_ref = create_node_ref::<Div>();
+    let form_id = resolve_id(id, "type-ahead");
+    let label_id = format!("{form_id}-label");
+    let feedback_id = format!("{form_id}-feedback");
+    let visible_err = visible_err(Arc::clone(&control), err_message);
+    let control_value = control.value;
+
     let (show_panel_reader, show_panel_writer) = create_signal(false);
 
     // handler to detect clicks outside then element and close open panels.
-    let _ = on_click_outside(type_ahead_ref, move |_| {
-        let is_panel_open = show_panel_reader.get_untracked();
+    let _ = on_click_outside(type_ahead_ref, {
+        let control_ref = Arc::clone(&control);
+
+        move |_| {
+            let is_panel_open = show_panel_reader.get_untracked();
+
+            if is_panel_open {
+                show_panel_writer.set(false);
+                control_ref.mark_as_touched();
+            }
+        }
+    });
+
+    // closes the panel, handing the focus back to the trigger
+    let on_close = Callback::new({
+        let control_ref = Arc::clone(&control);
 
-        if is_panel_open {
+        move |_| {
             show_panel_writer.set(false);
+            control_ref.mark_as_touched();
+
+            if let Some(trigger) = trigger_ref.get_untracked() {
+                let _ = trigger.focus();
+            }
         }
     });
 
@@ -57,129 +581,538 @@ pub fn TypeAhead(
         show_panel_writer.set(true);
     };
 
-    let on_select = Callback::from({
-        let control_ref = Arc::clone(&control);
+    let on_keydown = move |ev: KeyboardEvent| {
+        if opens_panel(&ev) {
+            ev.prevent_default();
+            show_panel_writer.set(true);
+        } else if let Some(on_trigger_keydown) = on_trigger_keydown {
+            on_trigger_keydown.call(ev);
+        }
+    };
 
-        move |option: TypeAheadOption| {
-            control_ref.set_value(Some(option));
-            show_panel_writer.set(false);
+    let on_select = Callback::new(move |option: T| {
+        on_select.call(option);
+
+        if close_on_select {
+            on_close.call(());
         }
     });
 
+    let on_clear_click = move |ev: MouseEvent| {
+        // keep the click from opening the panel
+        ev.stop_propagation();
+
+        if let Some(on_clear) = on_clear {
+            on_clear.call(());
+        }
+    };
+
+    // type ahead specific properties by validation state
+    let class_props_by_state = move || match visible_err.with(Option::is_some) {
+        true => {
+            format!("{DEFAULT_STYLE_CLASS} lumx-invalid border-red-600 focus-within:border-red-600")
+        }
+        false => format!("{DEFAULT_STYLE_CLASS} border-slate-200 focus-within:border-blue-600"),
+    };
+
+    // the feedback region only describes the control while it has content
+    let described_by = {
+        let feedback_id = feedback_id.clone();
+
+        move || (hint.is_some() || visible_err.with(Option::is_some)).then(|| feedback_id.clone())
+    };
+
     view! {
         <div node_ref=type_ahead_ref
             on:click=on_panel_click
-            class="lumx-type-ahead rounded-md border box-border border-slate-200 bg-white mb-2.5 focus-within:border focus-within:border-blue-600">
+            class=class_props_by_state>
 
-            <label class="lumx-type-ahead-relative relative">
+            <div class="lumx-type-ahead-relative relative">
+
+                <div class="type-ahead-control flex flex-row items-center gap-x-1 p-2.5">
+                    <div node_ref=trigger_ref
+                        id=form_id.clone()
+                        class="type-ahead-trigger grow flex flex-col outline-none"
+                        tabindex="0"
+                        role="button"
+                        aria-haspopup="listbox"
+                        aria-expanded=move || show_panel_reader.get().to_string()
+                        aria-invalid=move || visible_err.with(Option::is_some).to_string()
+                        aria-describedby=described_by
+                        on:keydown=on_keydown>
+                        <div id=label_id.clone() class="type-ahead-label text-xs text-slate-900">
+                            {label}
+                        </div>
 
-                <div class="type-ahead-control flex flex-col p-2.5">
-                    <div class="type-ahead-label text-xs text-slate-900">
-                        {label}
+                        <Show
+                            when=move || control_value.with(Option::is_some)
+                            fallback=move || view! {
+                                <div class="type-ahead-placeholder text-sm text-slate-400 min-h-5">
+                                    {placeholder}
+                                </div>
+                             }>
+                            {children()}
+                        </Show>
                     </div>
 
-                    <Show
-                        when=move || is_some(selected_value.get()).get()
-                        fallback=move || view! {
-                            <div class="type-ahead-placeholder text-sm text-slate-400 min-h-5">
-                                {placeholder}
-                            </div>
-                         }>
-                        <div class="type-ahead-value flex justify-between flex-row gap-y-1 min-h-5">
-                            <div class="text-sm text-slate-900">
-                                {selected_value.get().get().unwrap().label }
-                            </div>
-
-                            <div on:click={
-                                let control_ref = Arc::clone(&control);
-                                move |_ev: MouseEvent| {
-                                    control_ref.set_value(None);
-                                }
-                            } class="type-ahead-clear cursor-pointer rounded-full hover:bg-slate-200">
-                                <XMark class="w-5 h-5 text-slate-900" />
-                            </div>
-                        </div>
+                    <Show when=move || on_clear.is_some() && control_value.with(Option::is_some)>
+                        <button type="button"
+                            aria-label="clear"
+                            on:click=on_clear_click
+                            class="type-ahead-clear cursor-pointer rounded-full hover:bg-slate-200">
+                            <XMark class="w-5 h-5 text-slate-900" />
+                        </button>
                     </Show>
                 </div>
 
                 <Show when=move || show_panel_reader.get()>
                     <TypeAheadSearchPanel
+                        id=form_id.clone()
+                        label_id=label_id.clone()
+                        extractors=extractors
+                        option_template=option_template
+                        multiple=multiple
                         debounced_ms=debounced_ms
+                        min_chars=min_chars
+                        loading=loading.clone()
+                        empty=empty.clone()
+                        error=error.clone()
+                        min_chars_hint=min_chars_hint.clone()
                         on_select=on_select
+                        on_close=on_close
+                        is_selected=is_selected
+                        is_locked=is_locked
                         changes=changes
-                        suggestions=suggestions />
+                        matcher=matcher
+                        suggestions=suggestions.clone() />
                 </Show>
-            </label>
+            </div>
+
+            <div class="px-2.5 pb-1">
+                <FieldFeedback id=feedback_id hint=hint err=visible_err />
+            </div>
         </div>
     }
 }
 
+/// Search field and suggestions, following the WAI-ARIA combobox pattern: the search
+/// field owns the focus while the arrow keys move the active suggestion.
 #[component]
-fn TypeAheadSearchPanel(
+fn TypeAheadSearchPanel<T>(
+    /// id of the type ahead, prefixing the ids of the panel elements
+    id: String,
+    label_id: String,
+    extractors: Extractors<T>,
+    option_template: Option<Callback<TypeAheadOptionContext<T>, View>>,
+    /// several suggestions can be selected
+    #[prop(optional)]
+    multiple: bool,
     #[prop(default = 800.0)] debounced_ms: f64,
-    #[prop(into)] on_select: Callback<TypeAheadOption>,
-    changes: WriteSignal<String>,
-    suggestions: Resource<String, Vec<TypeAheadOption>>,
-) -> impl IntoView {
+    #[prop(into)] on_select: Callback<T>,
+    /// `Escape` was pressed or the focus left with `Tab`
+    #[prop(into)]
+    on_close: Callback<()>,
+    /// marks the suggestions already picked in multi mode
+    is_selected: Option<Callback<T, bool>>,
+    /// suggestions that can no longer be picked
+    is_locked: Option<Callback<T, bool>>,
+    min_chars: usize,
+    loading: Option<ChildrenFn>,
+    empty: Option<ChildrenFn>,
+    error: Option<ChildrenFn>,
+    min_chars_hint: Option<ChildrenFn>,
+    changes: Option<WriteSignal<String>>,
+    matcher: Matcher,
+    suggestions: TypeAheadSource<T>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+{
+    let input_ref = create_node_ref::<Input>();
+    let listbox_id = format!("{id}-listbox");
+    let status_id = format!("{id}-status");
     let (input_changes, input_changes_writer) = create_signal("".to_string());
+    let active = create_rw_signal(None::<usize>);
+
+    let option_id = move |idx: usize| format!("{id}-option-{idx}");
+
+    // the focus moves into the search field once the panel shows up
+    input_ref.on_load(|input| {
+        let _ = input.focus();
+    });
+
+    let too_short = move || input_changes.with(|typed| typed.trim().chars().count() < min_chars);
+
+    // a local list is never loading nor failing
+    let (result, refetch) = match suggestions {
+        TypeAheadSource::Remote { result, refetch } => (result, Some(refetch)),
+        TypeAheadSource::Local(options) => {
+            let filtered = Signal::derive(move || {
+                let query = input_changes.get();
+
+                options.with(|options| {
+                    Some(Ok(filter_options(
+                        options,
+                        |option| extractors.label(option),
+                        &query,
+                        matcher,
+                    )))
+                })
+            });
+
+            (filtered, None)
+        }
+    };
+
+    let options = Signal::derive(move || match too_short() {
+        true => vec![],
+        false => result.with(|result| match result {
+            Some(Ok(options)) => options.clone(),
+            _ => vec![],
+        }),
+    });
+
+    // text the shown suggestions were searched with, the typed one for a local list
+    let searched = create_rw_signal(None::<String>);
+
+    // short texts are not searched
+    if let Some(changes) = changes {
+        let _ = watch_debounced(
+            move || input_changes.get(),
+            move |input_value, _, _| {
+                if input_value.trim().chars().count() >= min_chars {
+                    searched.set(Some(input_value.to_string()));
+                    changes.set(input_value.to_string());
+                }
+            },
+            debounced_ms,
+        );
+    }
+
+    let highlighted = move || {
+        searched
+            .get_untracked()
+            .unwrap_or_else(|| input_changes.get_untracked())
+    };
+
+    // the active suggestion starts over with every new list
+    create_effect(move |_| {
+        options.track();
+        active.set(None);
+    });
+
+    let locked_flags = move || {
+        options.with_untracked(|options| {
+            options
+                .iter()
+                .map(|option| is_locked.is_some_and(|is_locked| is_locked.call(option.clone())))
+                .collect::<Vec<_>>()
+        })
+    };
 
     let on_input = move |ev| {
         input_changes_writer.set(event_target_value(&ev));
     };
 
-    let _ = watch_debounced(
-        move || input_changes.get(),
-        move |input_value, _, _| {
-            changes.set(input_value.to_string());
-        },
-        debounced_ms,
-    );
+    let select = move |idx: usize| {
+        let locked = locked_flags();
+
+        if locked.get(idx).is_some_and(|locked| !locked) {
+            if let Some(option) = options.with_untracked(|options| options.get(idx).cloned()) {
+                on_select.call(option);
+            }
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let locked = locked_flags();
+        let current = active.get_untracked().filter(|idx| *idx < locked.len());
+
+        match ev.key().as_str() {
+            "ArrowDown" => active.set(move_active(&locked, current, 1)),
+            "ArrowUp" => active.set(move_active(&locked, current, -1)),
+            "PageDown" => active.set(move_active(&locked, current, PAGE_SIZE as isize)),
+            "PageUp" => active.set(move_active(&locked, current, -(PAGE_SIZE as isize))),
+            "Home" if !locked.is_empty() => active.set(first_enabled(&locked)),
+            "End" if !locked.is_empty() => active.set(last_enabled(&locked)),
+            "Enter" => match current {
+                Some(idx) => select(idx),
+                None => return,
+            },
+            "Escape" => on_close.call(()),
+            "Tab" => {
+                on_close.call(());
+                return;
+            }
+            _ => return,
+        }
+
+        ev.prevent_default();
+    };
+
+    let expanded = move || options.with(|options| !options.is_empty());
+
+    let active_descendant = {
+        let option_id = option_id.clone();
+
+        move || active.get().filter(|_| expanded()).map(&option_id)
+    };
+
+    // keep the active suggestion visible while navigating
+    create_effect({
+        let option_id = option_id.clone();
+
+        move |_| {
+            if let Some(idx) = active.get() {
+                if let Some(element) = document().get_element_by_id(&option_id(idx)) {
+                    element.scroll_into_view_with_bool(false);
+                }
+            }
+        }
+    });
+
+    let render_options = move || {
+        options
+            .get()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                view! {
+                    <TypeAheadOptionView
+                        id=option_id(idx)
+                        item=item
+                        extractors=extractors
+                        option_template=option_template
+                        query=highlighted()
+                        active=Callback::new(move |_| active.get() == Some(idx))
+                        on_hover=Callback::new(move |_| {
+                            if active.get_untracked() != Some(idx) {
+                                active.set(Some(idx));
+                            }
+                        })
+                        on_select=on_select
+                        is_selected=is_selected
+                        is_locked=is_locked />
+                }
+            })
+            .collect_view()
+    };
+
+    let min_chars_message = min_chars_message(min_chars);
+
+    let loading_view = move || match &loading {
+        Some(loading) => loading().into_view(),
+        None => view! {
+            <div class="flex flex-row items-center gap-x-2">
+                <Spin class="w-4 h-4 animate-spin text-slate-500" />
+                <span>"Loading suggestions"</span>
+            </div>
+        }
+        .into_view(),
+    };
+
+    // what the panel shows besides the suggestions
+    let render_state = {
+        let min_chars_message = min_chars_message.clone();
+
+        move || {
+            if too_short() {
+                return match &min_chars_hint {
+                    Some(min_chars_hint) => min_chars_hint().into_view(),
+                    None => min_chars_message.clone().into_view(),
+                };
+            }
+
+            match result.get() {
+                Some(Err(message)) => view! {
+                    <div class="type-ahead-error flex flex-row items-center justify-between gap-x-2 text-red-600">
+                        <span>
+                            {match &error {
+                                Some(error) => error().into_view(),
+                                None => message.into_view(),
+                            }}
+                        </span>
+                        {refetch.map(|refetch| view! {
+                            <button type="button"
+                                on:click=move |_ev: MouseEvent| refetch.call(())
+                                class="type-ahead-retry shrink-0 cursor-pointer rounded-md px-2 py-0.5 text-xs font-semibold text-slate-900 hover:bg-slate-200">
+                                "Retry"
+                            </button>
+                        })}
+                    </div>
+                }
+                .into_view(),
+                Some(Ok(options)) if options.is_empty() => match &empty {
+                    Some(empty) => empty().into_view(),
+                    None => "No results".into_view(),
+                },
+                _ => View::default(),
+            }
+        }
+    };
+
+    // screen readers hear about every change of state
+    let status_message = move || {
+        if too_short() {
+            return Some(min_chars_message.clone());
+        }
+
+        match result.get()? {
+            Ok(options) => Some(result_count_message(options.len())),
+            Err(message) => Some(message),
+        }
+    };
 
     view! {
-        <div class="type-ahead-overlay-panel w-full absolute mt-1 z-10 rounded-md border box-border border-slate-200 bg-white">
+        <div class="type-ahead-overlay-panel w-full absolute mt-1 z-10 rounded-md border box-border border-slate-200 bg-white"
+            on:click=|ev: MouseEvent| ev.stop_propagation()>
             <div class="py-1 px-2 flex flex-col">
-                <input class="outline-none py-1 px-2 text-sm bg-white text-slate-900 rounded-md border border-slate-200"
+                <input node_ref=input_ref
+                    class="outline-none py-1 px-2 text-sm bg-white text-slate-900 rounded-md border border-slate-200"
                     type="text"
-                    on:input=on_input />
+                    role="combobox"
+                    autocomplete="off"
+                    aria-autocomplete="list"
+                    aria-controls=listbox_id.clone()
+                    aria-expanded=move || expanded().to_string()
+                    aria-activedescendant=active_descendant
+                    aria-labelledby=label_id.clone()
+                    aria-describedby=status_id.clone()
+                    on:input=on_input
+                    on:keydown=on_keydown />
             </div>
 
-            <ul class="type-ahead-suggestions"
-                role="menu"
-                aria-orientation="vertical"
-                aria-labelledby="menu-button"
+            <ul id=listbox_id
+                class="type-ahead-suggestions max-h-60 overflow-y-auto"
+                role="listbox"
+                aria-labelledby=label_id
+                aria-multiselectable=multiple.then_some("true")
                 tabindex="-1">
                 <Suspense>
-                    <For each=move || suggestions.get().unwrap_or_default()
-                        key=|i| i.value.clone()
-                        let:item>
-                        <TypeAheadOptionView
-                            item=item
-                            on_select=on_select />
-                    </For>
+                    {render_options.clone()}
                 </Suspense>
             </ul>
 
+            <div class="type-ahead-state px-3 pb-2 text-sm text-slate-500 empty:hidden">
+                <Suspense fallback=loading_view.clone()>
+                    {render_state.clone()}
+                </Suspense>
+            </div>
+
+            <div id=status_id class="sr-only" role="status" aria-live="polite">
+                <Suspense fallback=|| "Loading suggestions">
+                    {status_message.clone()}
+                </Suspense>
+            </div>
         </div>
     }
 }
 
 #[component]
-fn TypeAheadOptionView(
-    item: TypeAheadOption,
-    #[prop(into)] on_select: Callback<TypeAheadOption>,
-) -> impl IntoView {
-    let item_presenter = item.clone();
+fn TypeAheadOptionView<T>(
+    id: String,
+    item: T,
+    extractors: Extractors<T>,
+    option_template: Option<Callback<TypeAheadOptionContext<T>, View>>,
+    /// text typed in the search field
+    query: String,
+    /// the option is the one the arrow keys point to
+    active: Callback<(), bool>,
+    on_hover: Callback<()>,
+    #[prop(into)] on_select: Callback<T>,
+    is_selected: Option<Callback<T, bool>>,
+    is_locked: Option<Callback<T, bool>>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+{
+    let label = extractors.label(&item);
+
+    let content = match option_template {
+        Some(option_template) => option_template.call(TypeAheadOptionContext {
+            option: item.clone(),
+            label,
+            query,
+        }),
+        None => view! { <span><Highlight text=label query=query /></span> }.into_view(),
+    };
+
+    let selected = create_memo({
+        let item = item.clone();
+
+        move |_| is_selected.is_some_and(|is_selected| is_selected.call(item.clone()))
+    });
+
+    let locked = create_memo({
+        let item = item.clone();
+
+        move |_| is_locked.is_some_and(|is_locked| is_locked.call(item.clone()))
+    });
+
+    let is_active = move || active.call(());
 
     let on_click = move |ev: MouseEvent| {
         ev.prevent_default();
 
-        on_select.call(item.clone());
+        if !locked.get_untracked() {
+            on_select.call(item.clone());
+        }
     };
 
     view! {
-        <li class="type-ahead-option text-sm text-slate-900 py-2 px-2 hover:bg-slate-200 cursor-pointer"
+        <li id=id
+            role="option"
+            class="type-ahead-option flex flex-row items-center justify-between text-sm text-slate-900 py-2 px-2"
+            class=("bg-slate-200", is_active)
+            class=("cursor-pointer", move || !locked.get())
+            class=("opacity-50", move || locked.get())
+            aria-disabled=move || locked.get().then_some("true")
+            aria-selected=move || match is_selected {
+                Some(_) => selected.get().to_string(),
+                None => is_active().to_string(),
+            }
+            on:mousedown=|ev: MouseEvent| ev.prevent_default()
+            on:mousemove=move |_ev: MouseEvent| on_hover.call(())
             on:click=on_click>
-            <span>{item_presenter.label}</span>
+            {content}
+            <Show when=move || selected.get()>
+                <Check class="w-4 h-4 text-slate-900" />
+            </Show>
         </li>
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::type_ahead::{min_chars_message, result_count_message, toggle_keyed};
+
+    #[test]
+    fn it_announces_the_result_count() {
+        assert_eq!(result_count_message(0), "No results");
+        assert_eq!(result_count_message(1), "1 result available");
+        assert_eq!(result_count_message(12), "12 results available");
+    }
+
+    #[test]
+    fn it_hints_the_characters_to_type() {
+        assert_eq!(min_chars_message(1), "Type at least 1 character");
+        assert_eq!(min_chars_message(3), "Type at least 3 characters");
+    }
+
+    #[test]
+    fn it_toggles_the_options_by_key() {
+        let key = |option: &(u32, &str)| option.0.to_string();
+        let selected = vec![(1, "Ada")];
+
+        // a reloaded record is matched by its key
+        assert!(toggle_keyed(&selected, &(1, "Ada L."), key, None).is_empty());
+        assert_eq!(
+            toggle_keyed(&selected, &(2, "Alan"), key, None),
+            vec![(1, "Ada"), (2, "Alan")]
+        );
+        assert_eq!(
+            toggle_keyed(&selected, &(2, "Alan"), key, Some(1)),
+            selected
+        );
+    }
+}